@@ -0,0 +1,63 @@
+use aoc::{
+    registry::{self, Day},
+    runner::{self, Outcome, Parts},
+};
+use common::{InputLocator, YEAR};
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::{fs, hint::black_box, panic};
+
+/* Every day is benchmarked on its example and, when it is present in the inputs directory, on the
+ * real input. Parsing and each part are measured separately, but a part is only benchmarked if it
+ * solves the input in the first place: some parts only work on real inputs (day 20) or haven't
+ * been written (day 05).
+ *
+ * `cargo bench -p aoc -- day-16/input` runs a single group. */
+
+fn bench_input(c: &mut Criterion, day: &Day, name: &str, input: &str) {
+    let params = day.default_params();
+    let report = runner::run(day, input, Parts::Both, &params);
+    let Ok(Ok(parsed)) = panic::catch_unwind(|| (day.parse)(input)) else {
+        return;
+    };
+
+    let mut group = c.benchmark_group(format!("day-{:02}/{name}", day.day));
+    if name != "example" {
+        /* Real inputs take up to seconds per part, the default 100 samples would take ages. */
+        group.sample_size(10);
+    }
+
+    group.bench_function("parse", |b| b.iter(|| (day.parse)(black_box(input))));
+
+    for (part, outcome) in report.outcomes() {
+        if let Outcome::Solved(_) = outcome {
+            let solver = if part == 1 {
+                day.part_one
+            } else {
+                day.part_two
+            };
+            group.bench_function(format!("part {part}"), |b| {
+                b.iter(|| solver(black_box(&parsed), &params))
+            });
+        }
+    }
+
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    runner::capture_panics();
+    /* Only the inputs directory is used, a single AOC_INPUT can't belong to every day. */
+    let inputs = InputLocator::from_env();
+
+    for day in registry::days() {
+        if let Ok(example) = fs::read_to_string(day.example()) {
+            bench_input(c, &day, "example", &example);
+        }
+        if let Ok(input) = fs::read_to_string(inputs.path(YEAR, day.day)) {
+            bench_input(c, &day, "input", &input);
+        }
+    }
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
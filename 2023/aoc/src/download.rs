@@ -0,0 +1,258 @@
+use anyhow::{anyhow, bail, Context, Result};
+use common::InputLocator;
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+/// The session cookie of a logged in Advent of Code account, taking precedence over the config
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Where to download inputs from, taking precedence over the config
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = concat!(
+    "aoc-runner/",
+    env!("CARGO_PKG_VERSION"),
+    " (caches inputs, throttles submissions)"
+);
+
+/// Settings for talking to the Advent of Code site, read from `config.txt` in the inputs directory
+/// (which stays out of git along with the inputs), such as:
+///
+/// ```text
+/// # From the `session` cookie of the site once logged in
+/// session = 53616c7465645f5f...
+/// base_url = https://adventofcode.com
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_owned(),
+        }
+    }
+}
+
+impl Config {
+    pub fn path(inputs: &InputLocator) -> PathBuf {
+        inputs.inputs_dir().join("config.txt")
+    }
+
+    /// The config of the inputs directory if there is one, overridden by `AOC_SESSION` and
+    /// `AOC_BASE_URL`
+    pub fn load(inputs: &InputLocator) -> Result<Self> {
+        let path = Self::path(inputs);
+        let mut config = if path.is_file() {
+            let text = fs::read_to_string(&path)
+                .with_context(|| format!("Could not read {}", path.display()))?;
+            Self::parse(&text).with_context(|| format!("Invalid config {}", path.display()))?
+        } else {
+            Self::default()
+        };
+
+        if let Ok(session) = env::var(SESSION_VAR) {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = env::var(BASE_URL_VAR) {
+            config.base_url = base_url;
+        }
+        Ok(config)
+    }
+
+    /// The session cookie, which is needed for anything specific to an account
+    pub fn session(&self) -> Result<&str> {
+        self.session.as_deref().ok_or(anyhow!(
+            "No session cookie to talk to the site with (set {SESSION_VAR} or `session` in the \
+             config)"
+        ))
+    }
+
+    pub fn base_url(&self) -> String {
+        self.base_url.trim_end_matches('/').to_owned()
+    }
+
+    fn parse(text: &str) -> Result<Self> {
+        let mut config = Self::default();
+        for line in text.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or(anyhow!("Expected `KEY = VALUE`, got `{line}`"))?;
+            let value = value.trim().to_owned();
+            match key.trim() {
+                "session" => config.session = Some(value),
+                "base_url" => config.base_url = value,
+                key => bail!("Expected `session` or `base_url`, got `{key}`"),
+            }
+        }
+        Ok(config)
+    }
+}
+
+/// Fetches the inputs that aren't in the inputs directory yet. Once there, an input is never
+/// downloaded again: it doesn't change, and the site asks not to be hit more than needed.
+pub struct Downloader {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Downloader {
+    pub fn new(config: &Config) -> Result<Self> {
+        Ok(Self {
+            agent: agent(),
+            base_url: config.base_url(),
+            session: config.session()?.to_owned(),
+        })
+    }
+
+    pub fn fetch(&self, year: u16, day: u8) -> Result<String> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|err| match err {
+                ureq::Error::Status(404, _) => anyhow!("{url} is not available (yet?)"),
+                ureq::Error::Status(400, _) => anyhow!("The session cookie was refused by {url}"),
+                err => anyhow!("Could not download {url}: {err}"),
+            })?;
+
+        response
+            .into_string()
+            .with_context(|| format!("Could not read the response of {url}"))
+    }
+
+    /// Where the day's input is stored, after downloading it if it wasn't there yet
+    pub fn ensure(&self, inputs: &InputLocator, year: u16, day: u8) -> Result<PathBuf> {
+        let path = inputs.path(year, day);
+        if path.is_file() {
+            return Ok(path);
+        }
+
+        let input = self.fetch(year, day)?;
+        save(&path, &input)?;
+        Ok(path)
+    }
+}
+
+/// An HTTP client that introduces itself to the site
+pub(crate) fn agent() -> ureq::Agent {
+    ureq::AgentBuilder::new()
+        .user_agent(USER_AGENT)
+        .timeout(Duration::from_secs(30))
+        .build()
+}
+
+/* Written next to its destination and then moved there, so that an interrupted download doesn't
+ * leave a truncated input to be cached forever. */
+fn save(path: &Path, input: &str) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("Could not create {}", dir.display()))?;
+    }
+    let partial = path.with_extension("in.part");
+    fs::write(&partial, input).with_context(|| format!("Could not write {}", partial.display()))?;
+    fs::rename(&partial, path).with_context(|| format!("Could not write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fake_site::FakeSite;
+    use indoc::indoc;
+
+    /// Serves day 7 to the `secret` session only
+    fn fake_site() -> FakeSite {
+        FakeSite::start(
+            |request| match (request.url.as_str(), request.cookie.as_deref()) {
+                ("/2023/day/7/input", Some("session=secret")) => (200, "32T3K 765\n".to_owned()),
+                ("/2023/day/7/input", _) => (400, "Bad session".to_owned()),
+                _ => (404, "Not found".to_owned()),
+            },
+        )
+    }
+
+    fn inputs_dir(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("aoc-download-{name}-{}", std::process::id()))
+    }
+
+    #[test]
+    fn test_config() -> Result<()> {
+        let config = Config::parse(indoc! {"
+            # Copied from the browser
+            session = abc123
+        "})?;
+        assert_eq!(config.session.as_deref(), Some("abc123"));
+        assert_eq!(config.base_url, DEFAULT_BASE_URL);
+
+        assert!(Config::parse("sesion = abc123").is_err());
+        assert!(Downloader::new(&Config::default()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_download_once() -> Result<()> {
+        let site = fake_site();
+        let dir = inputs_dir("once");
+        let inputs = InputLocator::new(&dir);
+        let config = Config {
+            session: Some("secret".to_owned()),
+            base_url: site.base_url.clone() + "/",
+        };
+        let downloader = Downloader::new(&config)?;
+
+        let path = downloader.ensure(&inputs, 2023, 7)?;
+        assert_eq!(path, inputs.path(2023, 7));
+        assert_eq!(inputs.read(2023, 7)?, "32T3K 765\n");
+        assert_eq!(site.requests(), 1);
+
+        /* The cached input is used from then on, even if it was edited */
+        fs::write(&path, "KK677 28\n")?;
+        downloader.ensure(&inputs, 2023, 7)?;
+        assert_eq!(inputs.read(2023, 7)?, "KK677 28\n");
+        assert_eq!(site.requests(), 1);
+
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_failed_download_is_not_cached() -> Result<()> {
+        let site = fake_site();
+        let dir = inputs_dir("failed");
+        let inputs = InputLocator::new(&dir);
+
+        let stranger = Downloader::new(&Config {
+            session: Some("stolen".to_owned()),
+            base_url: site.base_url.clone(),
+        })?;
+        let refused = stranger.ensure(&inputs, 2023, 7).unwrap_err();
+        assert!(refused.to_string().contains("session cookie was refused"));
+
+        let owner = Downloader::new(&Config {
+            session: Some("secret".to_owned()),
+            base_url: site.base_url.clone(),
+        })?;
+        let locked = owner.ensure(&inputs, 2023, 25).unwrap_err();
+        assert!(locked.to_string().contains("not available"));
+
+        assert!(!inputs.path(2023, 7).exists());
+        assert!(!inputs.path(2023, 25).exists());
+        assert_eq!(site.requests(), 2);
+
+        let _ = fs::remove_dir_all(dir);
+        Ok(())
+    }
+}
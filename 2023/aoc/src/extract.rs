@@ -0,0 +1,289 @@
+use crate::{
+    registry::Day,
+    runner::{self, Outcome, Parts},
+    submit::strip_tags,
+};
+use anyhow::{bail, Context, Result};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// One part of a saved puzzle page: its blocks of preformatted text (the example inputs among
+/// them) and the answer highlighted last, which is the one to the example
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Article {
+    pub blocks: Vec<String>,
+    pub answer: Option<String>,
+}
+
+/// The parts described by the page, which only has the second one once the first is solved
+pub fn parse_page(html: &str) -> Result<Vec<Article>> {
+    let articles = html
+        .split("<article")
+        .skip(1)
+        .map(|rest| {
+            rest.split_once("</article>")
+                .map_or(rest, |(article, _)| article)
+        })
+        .map(|article| Article {
+            blocks: between(article, "<pre><code>", "</code></pre>")
+                .map(|block| {
+                    let block = strip_tags(block);
+                    if block.ends_with('\n') {
+                        block
+                    } else {
+                        block + "\n"
+                    }
+                })
+                .collect(),
+            answer: between(article, "<code><em>", "</em></code>")
+                .last()
+                .map(|answer| strip_tags(answer).trim().to_owned()),
+        })
+        .collect::<Vec<_>>();
+
+    if articles.is_empty() {
+        bail!("No puzzle description in the page, was it saved after logging in?");
+    }
+    Ok(articles)
+}
+
+fn between<'a>(text: &'a str, start: &'a str, end: &'a str) -> impl Iterator<Item = &'a str> {
+    text.split(start)
+        .skip(1)
+        .filter_map(move |rest| Some(rest.split_once(end)?.0))
+}
+
+/// The example input an answer goes with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Choice {
+    pub part: u8,
+    pub answer: String,
+    pub input: String,
+    /// Whether the day's solution finds the answer from the input with its default parameters
+    pub verified: bool,
+}
+
+/// The example input of each part with a highlighted answer, found by solving the blocks of the
+/// page with `day`. When none gives the answer, which happens when the example needs other
+/// parameters than the defaults, the first candidate is assumed.
+pub fn choose(day: &Day, articles: &[Article]) -> Vec<Choice> {
+    let mut choices: Vec<Choice> = Vec::new();
+    for (part, article) in (1..=2).zip(articles) {
+        let Some(answer) = &article.answer else {
+            continue;
+        };
+
+        /* Pages also show the steps of working the example out, which look like inputs, so the
+         * input of a part is the block its answer can be found from. Part two often reuses the
+         * example of part one, so that's tried first, then the blocks of the part itself. */
+        let mut candidates = Vec::new();
+        candidates.extend(choices.first().map(|choice| &choice.input));
+        candidates.extend(&article.blocks);
+        candidates.extend(articles.iter().flat_map(|article| &article.blocks));
+        let mut seen = Vec::new();
+        candidates.retain(|block| {
+            let new = !seen.contains(block);
+            seen.push(*block);
+            new
+        });
+
+        let parts = if part == 1 { Parts::One } else { Parts::Two };
+        let solves = |input: &str| {
+            let report = runner::run(day, input, parts, &day.default_params());
+            report.outcomes()[usize::from(part) - 1].1 == &Outcome::Solved(answer.clone())
+        };
+        let (input, verified) = match candidates.iter().find(|input| solves(input)) {
+            Some(input) => (input, true),
+            None => match candidates.first() {
+                Some(input) => (input, false),
+                None => continue,
+            },
+        };
+
+        choices.push(Choice {
+            part,
+            answer: answer.clone(),
+            input: (*input).clone(),
+            verified,
+        });
+    }
+    choices
+}
+
+/// An example and its answers, as written to `NAME.in` and `NAME.answers`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fixture {
+    pub name: String,
+    pub input: String,
+    pub answers: String,
+}
+
+/// One fixture per distinct input: `example`, then `example-2` and so on
+pub fn fixtures(choices: &[Choice]) -> Vec<Fixture> {
+    let mut fixtures: Vec<Fixture> = Vec::new();
+    for choice in choices {
+        let index = match fixtures.iter().position(|f| f.input == choice.input) {
+            Some(index) => index,
+            None => {
+                fixtures.push(Fixture {
+                    name: match fixtures.len() {
+                        0 => "example".to_owned(),
+                        n => format!("example-{}", n + 1),
+                    },
+                    input: choice.input.clone(),
+                    answers: String::new(),
+                });
+                fixtures.len() - 1
+            }
+        };
+
+        let answers = &mut fixtures[index].answers;
+        if !choice.verified {
+            answers.push_str(&format!(
+                "# Part {} isn't found from this input with the default parameters, which it may \
+                 need to override\n",
+                choice.part
+            ));
+        }
+        answers.push_str(&format!("part {} = {}\n", choice.part, choice.answer));
+    }
+    fixtures
+}
+
+/// Writes the fixtures into `dir`, refusing to replace existing ones unless `force` is set
+pub fn write(dir: &Path, fixtures: &[Fixture], force: bool) -> Result<Vec<PathBuf>> {
+    let files = fixtures
+        .iter()
+        .flat_map(|fixture| {
+            [
+                (dir.join(format!("{}.in", fixture.name)), &fixture.input),
+                (
+                    dir.join(format!("{}.answers", fixture.name)),
+                    &fixture.answers,
+                ),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    /* Checked before writing anything, so that a refusal doesn't leave half of the fixtures */
+    if let Some((path, _)) = files.iter().find(|(path, _)| path.exists() && !force) {
+        bail!("{} already exists", path.display());
+    }
+
+    fs::create_dir_all(dir).with_context(|| format!("Could not create {}", dir.display()))?;
+    for (path, contents) in &files {
+        fs::write(path, contents).with_context(|| format!("Could not write {}", path.display()))?;
+    }
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+    use std::env;
+
+    /// A puzzle page laid out like the site's, with a description per part
+    fn page(parts: &[(&[&str], &str)]) -> String {
+        let articles = parts
+            .iter()
+            .map(|(blocks, answer)| {
+                let blocks = blocks
+                    .iter()
+                    .map(|block| format!("<p>For example:</p>\n<pre><code>{block}</code></pre>\n"))
+                    .collect::<String>();
+                format!(
+                    "<article class=\"day-desc\"><h2>--- Part ---</h2>{blocks}<p>The answer is \
+                     <code><em>{answer}</em></code>.</p></article>\n"
+                )
+            })
+            .collect::<String>();
+        format!(
+            "<html><body><main>{articles}<p>Your puzzle answer was ...</p></main></body></html>"
+        )
+    }
+
+    #[test]
+    fn test_parse_page() -> Result<()> {
+        let html = page(&[(
+            &["a &lt;-&gt; <em>b</em> &amp;&amp; c\n", "12"],
+            "<em>3</em>",
+        )]);
+        assert_eq!(
+            parse_page(&html)?,
+            [Article {
+                blocks: vec!["a <-> b && c\n".to_owned(), "12\n".to_owned()],
+                answer: Some("3".to_owned()),
+            }]
+        );
+        assert!(parse_page("<html>Puzzle inputs differ by user.</html>").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_intermediate_steps_are_skipped() -> Result<()> {
+        let day = registry::find(14).unwrap();
+        let example = fs::read_to_string(day.example())?;
+        let tilted = example.replace("O.", ".O");
+        let html = page(&[(&[&example, &tilted], "136"), (&[&tilted], "64")]);
+
+        let choices = choose(&day, &parse_page(&html)?);
+        assert!(choices
+            .iter()
+            .all(|choice| choice.input == example && choice.verified));
+        assert_eq!(
+            fixtures(&choices),
+            [Fixture {
+                name: "example".to_owned(),
+                input: example,
+                answers: "part 1 = 136\npart 2 = 64\n".to_owned(),
+            }]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_example_for_part_two() -> Result<()> {
+        let day = registry::find(1).unwrap();
+        let example = fs::read_to_string(day.fixtures().join("example.in"))?;
+        let numerals = fs::read_to_string(day.fixtures().join("numerals.in"))?;
+        let html = page(&[(&[&example], "142"), (&[&numerals], "309")]);
+
+        let fixtures = fixtures(&choose(&day, &parse_page(&html)?));
+        assert_eq!(fixtures.len(), 2);
+        assert_eq!(fixtures[1].name, "example-2");
+        assert_eq!(fixtures[1].input, numerals);
+        assert_eq!(fixtures[1].answers, "part 2 = 309\n");
+
+        let dir = env::temp_dir().join(format!("aoc-extract-{}", std::process::id()));
+        assert_eq!(write(&dir, &fixtures, false)?.len(), 4);
+        assert!(write(&dir, &fixtures, false).is_err());
+        write(&dir, &fixtures, true)?;
+        assert_eq!(
+            fs::read_to_string(dir.join("example.answers"))?,
+            "part 1 = 142\n"
+        );
+
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_unverified_answer() -> Result<()> {
+        /* The example of day 11's part two expands the universe a hundredfold, not a millionfold */
+        let day = registry::find(11).unwrap();
+        let example = fs::read_to_string(day.example())?;
+        let html = page(&[(&[&example], "374"), (&[], "8410")]);
+
+        let choices = choose(&day, &parse_page(&html)?);
+        assert_eq!(choices.len(), 2);
+        assert!(choices[0].verified);
+        assert!(!choices[1].verified);
+        assert!(fixtures(&choices)[0]
+            .answers
+            .contains("# Part 2 isn't found from this input"));
+        Ok(())
+    }
+}
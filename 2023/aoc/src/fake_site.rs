@@ -0,0 +1,60 @@
+//! A stand-in for the Advent of Code site, for the tests of the clients that talk to it.
+
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+};
+use tiny_http::{Response, Server};
+
+/// What the site needs to know about a request to answer it
+pub struct Request {
+    pub url: String,
+    /// The value of the `Cookie` header, if any
+    pub cookie: Option<String>,
+    pub body: String,
+}
+
+/// A site on a local port, answering every request with the status and body `respond` gives
+pub struct FakeSite {
+    pub base_url: String,
+    requests: Arc<AtomicUsize>,
+}
+
+impl FakeSite {
+    pub fn start(respond: impl Fn(&Request) -> (u16, String) + Send + 'static) -> Self {
+        let server = Server::http("127.0.0.1:0").expect("The fake site should start");
+        let base_url = format!("http://{}", server.server_addr());
+        let requests = Arc::new(AtomicUsize::new(0));
+
+        let counter = Arc::clone(&requests);
+        thread::spawn(move || {
+            for mut request in server.incoming_requests() {
+                counter.fetch_add(1, Ordering::SeqCst);
+                let mut body = String::new();
+                let _ = request.as_reader().read_to_string(&mut body);
+                let cookie = request
+                    .headers()
+                    .iter()
+                    .find(|header| header.field.equiv("Cookie"))
+                    .map(|header| header.value.to_string());
+
+                let (status, body) = respond(&Request {
+                    url: request.url().to_owned(),
+                    cookie,
+                    body,
+                });
+                let _ = request.respond(Response::from_string(body).with_status_code(status));
+            }
+        });
+
+        Self { base_url, requests }
+    }
+
+    /// How many requests the site got so far
+    pub fn requests(&self) -> usize {
+        self.requests.load(Ordering::SeqCst)
+    }
+}
@@ -0,0 +1,355 @@
+use crate::{
+    download::{self, Config},
+    summary,
+};
+use anyhow::{anyhow, Context, Result};
+use itertools::Itertools;
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+/// The site asks not to fetch a leaderboard more often than this
+pub const REFRESH: Duration = Duration::from_secs(15 * 60);
+
+/// A private leaderboard, as served by the site's JSON API
+#[derive(Debug, Clone, Deserialize)]
+pub struct Leaderboard {
+    /// The year of the event, such as `"2023"`
+    pub event: String,
+    /// By member id
+    pub members: BTreeMap<String, Member>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    /// Missing for anonymous users
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u64,
+    #[serde(default)]
+    pub last_star_ts: u64,
+    /// When each part of each day was solved, by day and part
+    pub completion_day_level: BTreeMap<u8, BTreeMap<u8, Star>>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct Star {
+    pub get_star_ts: u64,
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    /// When the member got the star of a part, in seconds since the epoch
+    pub fn star(&self, day: u8, part: u8) -> Option<u64> {
+        let star = self.completion_day_level.get(&day)?.get(&part)?;
+        Some(star.get_star_ts)
+    }
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> Result<Self> {
+        serde_json::from_str(json).context("Invalid leaderboard JSON")
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let json = fs::read_to_string(path)
+            .with_context(|| format!("Could not read {}", path.display()))?;
+        Self::parse(&json).with_context(|| format!("Invalid leaderboard {}", path.display()))
+    }
+
+    pub fn year(&self) -> Result<u16> {
+        self.event
+            .parse()
+            .map_err(|_| anyhow!("Expected a year as the event, got `{}`", self.event))
+    }
+
+    /// The members by local score, then by stars, then by who got their last star first, each
+    /// with their rank. Members with the same local score share a rank.
+    pub fn ranking(&self) -> Vec<(usize, &Member)> {
+        let mut members = self.members.values().collect::<Vec<_>>();
+        members.sort_by_key(|member| {
+            (
+                std::cmp::Reverse(member.local_score),
+                std::cmp::Reverse(member.stars),
+                member.last_star_ts,
+                member.id,
+            )
+        });
+
+        let mut ranking: Vec<(usize, &Member)> = Vec::with_capacity(members.len());
+        for (i, member) in members.iter().enumerate() {
+            let rank = match ranking.last() {
+                Some(&(rank, previous)) if previous.local_score == member.local_score => rank,
+                _ => i + 1,
+            };
+            ranking.push((rank, *member));
+        }
+        ranking
+    }
+
+    /// The table of the ranking, with a `*` for every day solved and a `+` for every day only
+    /// half solved
+    pub fn standings(&self) -> String {
+        let header = ["#", "Score", "Stars", "Days 1-25", "Name"].map(str::to_owned);
+        let rows = self
+            .ranking()
+            .into_iter()
+            .map(|(rank, member)| {
+                let days = (1..=25)
+                    .map(|day| match (member.star(day, 1), member.star(day, 2)) {
+                        (Some(_), Some(_)) => '*',
+                        (Some(_), None) => '+',
+                        _ => '.',
+                    })
+                    .collect();
+                [
+                    rank.to_string(),
+                    member.local_score.to_string(),
+                    member.stars.to_string(),
+                    days,
+                    member.display_name(),
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        /* The names are left-aligned in the last column, which leaves trailing spaces */
+        summary::columns(&header, &rows, |col| col <= 2)
+            .iter()
+            .map(|line| line.trim_end())
+            .join("\n")
+    }
+
+    /// The table of the members who got a star on `day`, fastest first, with how long after the
+    /// puzzle unlocked they solved each part and how long part two took them
+    pub fn day(&self, day: u8) -> Result<String> {
+        let unlock = unlock_time(self.year()?, day);
+        let mut solvers = self
+            .members
+            .values()
+            .filter_map(|member| Some((member, member.star(day, 1)?, member.star(day, 2))))
+            .collect::<Vec<_>>();
+        solvers.sort_by_key(|&(member, one, two)| (two.unwrap_or(u64::MAX), one, member.id));
+
+        let since = |time: u64| elapsed(time.saturating_sub(unlock));
+        let header = ["Name", "Part 1", "Part 2", "Delta"].map(str::to_owned);
+        let rows = solvers
+            .into_iter()
+            .map(|(member, one, two)| {
+                [
+                    member.display_name(),
+                    since(one),
+                    two.map_or("-".to_owned(), since),
+                    two.map_or("-".to_owned(), |two| elapsed(two.saturating_sub(one))),
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        Ok(summary::columns(&header, &rows, |col| col > 0).join("\n"))
+    }
+}
+
+/// When the puzzle of `day` unlocks: midnight in the site's timezone (UTC-5), in seconds since
+/// the epoch
+pub fn unlock_time(year: u16, day: u8) -> u64 {
+    let year = u64::from(year);
+    let leap_days = |year: u64| year / 4 - year / 100 + year / 400;
+    let is_leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+
+    /* Whole years since 1970, then January to November */
+    let days = (year - 1970) * 365 + leap_days(year - 1) - leap_days(1969)
+        + 334
+        + u64::from(is_leap)
+        + u64::from(day - 1);
+    days * 86400 + 5 * 3600
+}
+
+/// `hh:mm:ss`, with the days in front when it took more than one
+fn elapsed(seconds: u64) -> String {
+    let (days, seconds) = (seconds / 86400, seconds % 86400);
+    let time = format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    );
+    if days > 0 {
+        format!("{days}d {time}")
+    } else {
+        time
+    }
+}
+
+/// Fetches private leaderboards, keeping a copy of each next to the inputs that is reused for
+/// as long as the site asks not to fetch it again
+pub struct Fetcher {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Fetcher {
+    pub fn new(config: &Config) -> Result<Self> {
+        Ok(Self {
+            agent: download::agent(),
+            base_url: config.base_url(),
+            session: config.session()?.to_owned(),
+        })
+    }
+
+    pub fn fetch(&self, year: u16, id: u64) -> Result<String> {
+        let url = format!(
+            "{}/{year}/leaderboard/private/view/{id}.json",
+            self.base_url
+        );
+        self.agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|err| match err {
+                ureq::Error::Status(404, _) => anyhow!("{url} doesn't exist"),
+                err => anyhow!("Could not fetch {url}: {err}"),
+            })?
+            .into_string()
+            .with_context(|| format!("Could not read the response of {url}"))
+    }
+
+    /// Where the leaderboard is kept, after fetching it again if the copy there is missing or
+    /// older than [`REFRESH`]
+    pub fn ensure(&self, dir: &Path, year: u16, id: u64) -> Result<PathBuf> {
+        let path = dir
+            .join(year.to_string())
+            .join(format!("leaderboard-{id}.json"));
+        let fresh = fs::metadata(&path)
+            .and_then(|metadata| metadata.modified())
+            .is_ok_and(|modified| modified.elapsed().is_ok_and(|age| age < REFRESH));
+        if fresh {
+            return Ok(path);
+        }
+
+        let json = self.fetch(year, id)?;
+        /* Checked before replacing the copy, which is better than nothing if the site is down */
+        Leaderboard::parse(&json)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("Could not create {}", dir.display()))?;
+        }
+        fs::write(&path, json).with_context(|| format!("Could not write {}", path.display()))?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fake_site::FakeSite;
+    use indoc::indoc;
+    use std::env;
+
+    const JSON: &str = indoc! {r#"
+        {
+          "event": "2023",
+          "owner_id": 1,
+          "members": {
+            "1": {
+              "id": 1, "name": "Alice", "stars": 3, "local_score": 11, "global_score": 0,
+              "last_star_ts": 1701494100,
+              "completion_day_level": {
+                "1": {
+                  "1": {"get_star_ts": 1701407100, "star_index": 0},
+                  "2": {"get_star_ts": 1701407700, "star_index": 1}
+                },
+                "2": {"1": {"get_star_ts": 1701494100, "star_index": 5}}
+              }
+            },
+            "2": {
+              "id": 2, "name": null, "stars": 2, "local_score": 5, "global_score": 0,
+              "last_star_ts": 1701500000,
+              "completion_day_level": {
+                "1": {
+                  "1": {"get_star_ts": 1701410400, "star_index": 2},
+                  "2": {"get_star_ts": 1701500000, "star_index": 6}
+                }
+              }
+            },
+            "3": {
+              "id": 3, "name": "Carol", "stars": 1, "local_score": 5, "global_score": 0,
+              "last_star_ts": 1701407400,
+              "completion_day_level": {
+                "1": {"1": {"get_star_ts": 1701407400, "star_index": 3}}
+              }
+            }
+          }
+        }
+    "#};
+
+    #[test]
+    fn test_unlock_time() {
+        assert_eq!(unlock_time(2023, 1), 1701406800);
+        assert_eq!(unlock_time(2020, 25), 1608872400);
+    }
+
+    #[test]
+    fn test_standings() -> Result<()> {
+        let leaderboard = Leaderboard::parse(JSON)?;
+        assert_eq!(
+            leaderboard.standings(),
+            indoc! {"
+                # | Score | Stars | Days 1-25                 | Name
+                --+-------+-------+---------------------------+--------------------
+                1 |    11 |     3 | *+....................... | Alice
+                2 |     5 |     2 | *........................ | (anonymous user #2)
+                2 |     5 |     1 | +........................ | Carol"}
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_day() -> Result<()> {
+        let leaderboard = Leaderboard::parse(JSON)?;
+        assert_eq!(
+            leaderboard.day(1)?,
+            indoc! {"
+                Name                |   Part 1 |      Part 2 |       Delta
+                --------------------+----------+-------------+------------
+                Alice               | 00:05:00 |    00:15:00 |    00:10:00
+                (anonymous user #2) | 01:00:00 | 1d 01:53:20 | 1d 00:53:20
+                Carol               | 00:10:00 |           - |           -"}
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_fetch_once() -> Result<()> {
+        let site = FakeSite::start(|request| match request.url.as_str() {
+            "/2023/leaderboard/private/view/1.json" => (200, JSON.to_owned()),
+            _ => (404, "Not found".to_owned()),
+        });
+
+        let dir = env::temp_dir().join(format!("aoc-leaderboard-{}", std::process::id()));
+        let fetcher = Fetcher::new(&Config {
+            session: Some("secret".to_owned()),
+            base_url: site.base_url.clone(),
+        })?;
+
+        let path = fetcher.ensure(&dir, 2023, 1)?;
+        assert_eq!(Leaderboard::load(&path)?.members.len(), 3);
+        fetcher.ensure(&dir, 2023, 1)?;
+        assert_eq!(site.requests(), 1);
+
+        assert!(fetcher.ensure(&dir, 2023, 2).is_err());
+        assert!(!dir.join("2023/leaderboard-2.json").exists());
+
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+}
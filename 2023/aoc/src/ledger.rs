@@ -0,0 +1,203 @@
+use crate::runner::{Outcome, Report};
+use anyhow::{anyhow, Context, Result};
+use std::{collections::BTreeMap, fmt::Display, fs, io, path::Path, str::FromStr};
+
+/// Known good answers, keyed by year, day and part.
+///
+/// It is stored as plain text with one `year day part answer` line per answer, so that it can be
+/// read and fixed by hand.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Ledger {
+    answers: BTreeMap<(u16, u8, u8), String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub day: u8,
+    pub part: u8,
+    pub expected: String,
+    pub actual: Outcome,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let actual = match &self.actual {
+            Outcome::Solved(answer) => answer.as_str(),
+            Outcome::Failed(_) => "a failure",
+            Outcome::Skipped => "nothing",
+        };
+        write!(
+            f,
+            "day {:02} part {}: expected {}, got {actual}",
+            self.day, self.part, self.expected
+        )
+    }
+}
+
+impl Ledger {
+    /// Loads the ledger at `path`, which is empty if the file doesn't exist yet
+    pub fn load(path: &Path) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => contents
+                .parse()
+                .with_context(|| format!("Invalid ledger {}", path.display())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err).with_context(|| format!("Could not read {}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_string())
+            .with_context(|| format!("Could not write {}", path.display()))
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(year, day, part)).map(String::as_str)
+    }
+
+    pub fn record(&mut self, year: u16, day: u8, part: u8, answer: impl Into<String>) {
+        self.answers.insert((year, day, part), answer.into());
+    }
+
+    /// Records every solved part, returning how many answers were recorded
+    pub fn record_reports(&mut self, year: u16, reports: &[Report]) -> usize {
+        let mut recorded = 0;
+        for report in reports {
+            for (part, outcome) in report.outcomes() {
+                if let Outcome::Solved(answer) = outcome {
+                    self.record(year, report.day, part, answer.clone());
+                    recorded += 1;
+                }
+            }
+        }
+        recorded
+    }
+
+    /// Every part that was run and whose outcome differs from the recorded answer
+    pub fn mismatches(&self, year: u16, reports: &[Report]) -> Vec<Mismatch> {
+        reports
+            .iter()
+            .flat_map(|report| {
+                report
+                    .outcomes()
+                    .into_iter()
+                    .filter(|(_, outcome)| **outcome != Outcome::Skipped)
+                    .filter_map(move |(part, outcome)| {
+                        let expected = self.get(year, report.day, part)?;
+                        (*outcome != Outcome::Solved(expected.to_owned())).then(|| Mismatch {
+                            day: report.day,
+                            part,
+                            expected: expected.to_owned(),
+                            actual: outcome.clone(),
+                        })
+                    })
+            })
+            .collect()
+    }
+
+    /// Every part that was solved but has no recorded answer yet
+    pub fn unrecorded(&self, year: u16, reports: &[Report]) -> Vec<(u8, u8)> {
+        reports
+            .iter()
+            .flat_map(|report| {
+                report
+                    .outcomes()
+                    .into_iter()
+                    .filter(|(part, outcome)| {
+                        matches!(outcome, Outcome::Solved(_))
+                            && self.get(year, report.day, *part).is_none()
+                    })
+                    .map(|(part, _)| (report.day, part))
+            })
+            .collect()
+    }
+}
+
+impl FromStr for Ledger {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut ledger = Self::default();
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields = line.splitn(4, ' ').collect::<Vec<_>>();
+            let [year, day, part, answer] = fields[..] else {
+                return Err(anyhow!(
+                    "Expected `year day part answer` on line {}: {line}",
+                    i + 1
+                ));
+            };
+
+            ledger.record(
+                year.parse()?,
+                day.parse()?,
+                part.parse()?,
+                answer.to_owned(),
+            );
+        }
+
+        Ok(ledger)
+    }
+}
+
+impl Display for Ledger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# year day part answer")?;
+        for ((year, day, part), answer) in &self.answers {
+            writeln!(f, "{year} {day:02} {part} {answer}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::Measurements;
+
+    #[test]
+    fn test_mismatches() -> Result<()> {
+        let ledger: Ledger = "2023 01 1 142\n2023 01 2 281\n2023 05 1 35\n".parse()?;
+        assert_eq!(ledger.to_string().parse::<Ledger>()?, ledger);
+
+        let reports = [
+            Report {
+                day: 1,
+                example: None,
+                part_one: Outcome::Solved("142".to_owned()),
+                part_two: Outcome::Solved("280".to_owned()),
+                measurements: Measurements::default(),
+            },
+            Report {
+                day: 5,
+                example: None,
+                part_one: Outcome::Failed("panicked".to_owned()),
+                part_two: Outcome::Solved("46".to_owned()),
+                measurements: Measurements::default(),
+            },
+        ];
+
+        let mismatches = ledger
+            .mismatches(2023, &reports)
+            .iter()
+            .map(Mismatch::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            mismatches,
+            [
+                "day 01 part 2: expected 281, got 280",
+                "day 05 part 1: expected 35, got a failure"
+            ]
+        );
+        assert_eq!(ledger.unrecorded(2023, &reports), [(5, 2)]);
+
+        Ok(())
+    }
+}
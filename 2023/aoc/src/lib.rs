@@ -0,0 +1,15 @@
+//! The pieces of the `aoc` runner, shared with its benchmarks.
+
+pub mod download;
+pub mod extract;
+#[cfg(test)]
+mod fake_site;
+pub mod leaderboard;
+pub mod ledger;
+pub mod output;
+pub mod registry;
+pub mod runner;
+pub mod scaffold;
+pub mod submit;
+pub mod summary;
+pub mod watch;
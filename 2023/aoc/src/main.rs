@@ -0,0 +1,742 @@
+use anyhow::{anyhow, bail, Context, Result};
+use aoc::{
+    download::{Config, Downloader},
+    extract,
+    leaderboard::{Fetcher, Leaderboard},
+    ledger::Ledger,
+    output::{self, Format},
+    registry,
+    registry::Day,
+    runner,
+    runner::{Outcome, Parts, Report},
+    scaffold,
+    submit::{History, Submitter, Verdict},
+    summary,
+    watch::Watch,
+};
+use clap::{Args, Parser, Subcommand};
+use common::{Input, InputLocator, INPUT_VAR, YEAR};
+use std::{fs, path::PathBuf, process::ExitCode, thread, time::Instant};
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code solutions in this workspace")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve the given days and print a summary table
+    Run(RunArgs),
+    /// Solve the given days and save their answers in the ledger
+    Record(LedgerArgs),
+    /// Solve the given days and compare their answers with the ledger
+    Verify(LedgerArgs),
+    /// List the available days and their parameters
+    List,
+    /// Create a new day crate with a stub solution and register it with the runner
+    New(NewArgs),
+    /// Make up a random input for a day, to stress test or benchmark it beyond the real one
+    Gen(GenArgs),
+    /// Rebuild and rerun a day, and check its examples, whenever its crate or its input changes
+    Watch(WatchArgs),
+    /// Submit the answer to a part, solving it first if it isn't given
+    Submit(SubmitArgs),
+    /// Check that an input lives up to what the solutions assume about it, to know whether their
+    /// answers can be trusted
+    CheckInput(CheckInputArgs),
+    /// Turn the examples of a saved puzzle page into fixtures of the day
+    Extract(ExtractArgs),
+    /// Show the standings of a private leaderboard, fetched from the site or from a saved copy
+    Leaderboard(LeaderboardArgs),
+}
+
+#[derive(Args)]
+struct CheckInputArgs {
+    year: u16,
+
+    /// Days to check, e.g. `aoc check-input 2023 8 21`
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    days: Vec<u8>,
+
+    /// Check every day that has an input and assumptions about it
+    #[arg(long)]
+    all: bool,
+
+    /// Input file of the (single) selected day, or `-` to read it from stdin
+    #[arg(long, conflicts_with = "all")]
+    input: Option<String>,
+
+    /// Directory containing the input files as `<year>/day-NN.in`
+    #[arg(long)]
+    inputs: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct ExtractArgs {
+    year: u16,
+
+    day: u8,
+
+    /// The puzzle page as saved from the browser
+    page: PathBuf,
+
+    /// Replace the fixtures of the same names
+    #[arg(long)]
+    force: bool,
+}
+
+#[derive(Args)]
+struct LeaderboardArgs {
+    year: u16,
+
+    /// The id of the leaderboard, the number at the end of its URL
+    #[arg(required_unless_present = "file")]
+    id: Option<u64>,
+
+    /// A saved copy of the leaderboard's JSON to read instead of fetching it
+    #[arg(long, conflicts_with = "id")]
+    file: Option<PathBuf>,
+
+    /// Also show when everyone solved the parts of this day
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// Directory containing the input files, where fetched leaderboards are kept too
+    #[arg(long)]
+    inputs: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    year: u16,
+
+    day: u8,
+
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// The answer to submit instead of the one the solution finds
+    answer: Option<String>,
+
+    /// Directory containing the input files as `<year>/day-NN.in`
+    #[arg(long)]
+    inputs: Option<PathBuf>,
+
+    /// Submissions file, `submissions.txt` in the inputs directory by default
+    #[arg(long)]
+    history: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct WatchArgs {
+    year: u16,
+
+    day: u8,
+
+    /// Input file of the day
+    #[arg(long)]
+    input: Option<PathBuf>,
+
+    /// Directory containing the input files as `<year>/day-NN.in`
+    #[arg(long)]
+    inputs: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct GenArgs {
+    year: u16,
+
+    day: u8,
+
+    /// The same seed and size always give the same input
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// Roughly how many lines or rows the input has
+    #[arg(long, default_value_t = 100)]
+    size: usize,
+
+    /// Write the input to this file instead of stdout
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    year: u16,
+
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// The puzzle's title, e.g. "Sand Slabs"
+    title: String,
+}
+
+#[derive(Args)]
+struct LedgerArgs {
+    #[command(flatten)]
+    run: RunArgs,
+
+    /// Answers file, `answers.txt` in the inputs directory by default
+    #[arg(long)]
+    ledger: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct RunArgs {
+    year: u16,
+
+    /// Days to run, e.g. `aoc run 2023 1 14`
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    days: Vec<u8>,
+
+    /// Run every day of the year
+    #[arg(long)]
+    all: bool,
+
+    /// Only run one of the two parts
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Input file of the (single) selected day, or `-` to read it from stdin
+    #[arg(long, conflicts_with = "all")]
+    input: Option<String>,
+
+    /// Directory containing the input files as `<year>/day-NN.in`
+    #[arg(long)]
+    inputs: Option<PathBuf>,
+
+    /// Override a day parameter, e.g. `--param steps=6`
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+    params: Vec<(String, String)>,
+
+    /// Solve the puzzle examples in each day's `fixtures` instead, and check their answers
+    #[arg(long, conflicts_with_all = ["input", "inputs", "params"])]
+    example: bool,
+
+    /// How to print the results. With `json` and `tsv`, any other message goes to stderr
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+
+    /// How many days to solve at the same time, one per core by default. Always one with
+    /// `alloc-stats`, whose counters are process-wide.
+    #[arg(long, short, value_parser = clap::value_parser!(u16).range(1..))]
+    jobs: Option<u16>,
+}
+
+fn parse_param(s: &str) -> Result<(String, String)> {
+    let (name, value) = s
+        .split_once('=')
+        .ok_or(anyhow!("Expected `NAME=VALUE`, got `{s}`"))?;
+    Ok((name.to_owned(), value.to_owned()))
+}
+
+fn main() -> Result<ExitCode> {
+    match Cli::parse().command {
+        Command::Run(args) => run(args),
+        Command::Record(args) => record(args),
+        Command::Verify(args) => verify(args),
+        Command::List => {
+            list();
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::New(args) => {
+            new(args)?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Gen(args) => {
+            gen(args)?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Watch(args) => {
+            watch(args)?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Submit(args) => submit(args),
+        Command::CheckInput(args) => check_input(args),
+        Command::Extract(args) => {
+            extract(args)?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Leaderboard(args) => {
+            leaderboard(args)?;
+            Ok(ExitCode::SUCCESS)
+        }
+    }
+}
+
+fn new(args: NewArgs) -> Result<()> {
+    if args.year != YEAR {
+        bail!("This workspace only holds solutions for {YEAR}");
+    }
+
+    let workspace = scaffold::workspace_dir();
+    for path in scaffold::new_day(&workspace, args.day, &args.title)? {
+        let path = path.strip_prefix(&workspace).unwrap_or(&path);
+        println!("Wrote {}", path.display());
+    }
+    println!(
+        "\nPaste the example into day-{:02}/fixtures/example.in and its answers next to it, then \
+         `cargo test -p day-{:02}`",
+        args.day, args.day
+    );
+    Ok(())
+}
+
+fn gen(args: GenArgs) -> Result<()> {
+    if args.year != YEAR {
+        bail!("No solutions for year {}", args.year);
+    }
+    if args.size == 0 {
+        bail!("The size of the input must be at least 1");
+    }
+
+    let day = registry::find(args.day).ok_or(anyhow!("No solution for day {}", args.day))?;
+    let input = day.generate_input(args.seed, args.size)?;
+    match &args.output {
+        Some(path) => {
+            fs::write(path, input).with_context(|| format!("Could not write {}", path.display()))?
+        }
+        None => print!("{input}"),
+    }
+    Ok(())
+}
+
+fn watch(args: WatchArgs) -> Result<()> {
+    if args.year != YEAR {
+        bail!("No solutions for year {}", args.year);
+    }
+    registry::find(args.day).ok_or(anyhow!("No solution for day {}", args.day))?;
+
+    let mut inputs = InputLocator::from_env();
+    if let Some(path) = &args.input {
+        inputs = inputs.with_explicit(Input::File(path.clone()));
+    }
+    if let Some(dir) = &args.inputs {
+        inputs = inputs.with_inputs_dir(dir);
+    }
+    let input = match inputs.explicit() {
+        Some(Input::File(path)) => path.clone(),
+        Some(Input::Stdin) => bail!("Can't watch stdin, give an input file instead"),
+        None => inputs.path(YEAR, args.day),
+    };
+
+    Watch::new(&scaffold::workspace_dir(), args.day, &input)?.run()
+}
+
+fn submit(args: SubmitArgs) -> Result<ExitCode> {
+    if args.year != YEAR {
+        bail!("No solutions for year {}", args.year);
+    }
+    let day = registry::find(args.day).ok_or(anyhow!("No solution for day {}", args.day))?;
+
+    let mut inputs = InputLocator::from_env();
+    if let Some(dir) = &args.inputs {
+        inputs = inputs.with_inputs_dir(dir);
+    }
+
+    let answer = match &args.answer {
+        Some(answer) => answer.clone(),
+        None => {
+            download_missing(&[day], &inputs, Format::Table)?;
+            let parts = match args.part {
+                1 => Parts::One,
+                _ => Parts::Two,
+            };
+            let report = runner::run_with_inputs(&day, &inputs, parts, &day.default_params());
+            let (_, outcome) = report.outcomes()[usize::from(args.part) - 1];
+            match outcome.clone() {
+                Outcome::Solved(answer) => answer,
+                Outcome::Failed(message) => bail!("Could not solve part {}: {message}", args.part),
+                Outcome::Skipped => unreachable!("The part was selected"),
+            }
+        }
+    };
+
+    let history_path = args
+        .history
+        .clone()
+        .unwrap_or_else(|| inputs.inputs_dir().join("submissions.txt"));
+    let mut history = History::load(&history_path)?;
+    let submitter = Submitter::new(&Config::load(&inputs)?)?;
+
+    println!(
+        "Submitting {answer} for {YEAR} day {:02} part {}",
+        args.day, args.part
+    );
+    let submission = submitter.submit(&mut history, YEAR, args.day, args.part, &answer)?;
+    history.save(&history_path)?;
+
+    let verdict = match submission.verdict {
+        Verdict::Correct => "That's the right answer!",
+        Verdict::TooHigh => "That's not the right answer, it's too high",
+        Verdict::TooLow => "That's not the right answer, it's too low",
+        Verdict::Wrong => "That's not the right answer",
+        Verdict::TooSoon => "It was too soon after the previous answer to submit another one",
+        Verdict::WrongLevel => "This part is either solved already or still locked",
+    };
+    println!("{verdict}");
+    if !submission.wait.is_zero() {
+        println!("Wait {:?} before submitting again", submission.wait);
+    }
+
+    if submission.verdict == Verdict::Correct {
+        let ledger_path = inputs.inputs_dir().join("answers.txt");
+        let mut ledger = Ledger::load(&ledger_path)?;
+        ledger.record(YEAR, args.day, args.part, answer);
+        ledger.save(&ledger_path)?;
+        println!("Recorded it in {}", ledger_path.display());
+    }
+
+    Ok(exit_code(submission.verdict == Verdict::Correct))
+}
+
+fn check_input(args: CheckInputArgs) -> Result<ExitCode> {
+    if args.year != YEAR {
+        bail!("No solutions for year {}", args.year);
+    }
+    let selected = if args.all {
+        registry::days()
+    } else {
+        args.days
+            .iter()
+            .map(|&day| registry::find(day).ok_or(anyhow!("No solution for day {day}")))
+            .collect::<Result<Vec<_>>>()?
+    };
+
+    let mut inputs = InputLocator::from_env();
+    if let Some(input) = &args.input {
+        inputs = inputs.with_explicit(Input::from_arg(input));
+    }
+    if let Some(dir) = &args.inputs {
+        inputs = inputs.with_inputs_dir(dir);
+    }
+    if inputs.explicit().is_some() && selected.len() > 1 {
+        bail!("A single input file (--input or {INPUT_VAR}) can only be used with one day");
+    }
+    if inputs.explicit().is_none() {
+        download_missing(&selected, &inputs, Format::Table)?;
+    }
+
+    let mut all_hold = true;
+    for day in &selected {
+        /* Checking every day is about the ones with an input and something to check about it */
+        let assumptions = match inputs.read(YEAR, day.day) {
+            Ok(input) => day.check_input(&input),
+            Err(_) if args.all => continue,
+            Err(err) => Err(err),
+        };
+        if args.all && assumptions.as_ref().is_ok_and(Vec::is_empty) {
+            continue;
+        }
+
+        println!("Day {:02}: {}", day.day, day.title);
+        match assumptions {
+            Ok(assumptions) if assumptions.is_empty() => println!("  Nothing to check"),
+            Ok(assumptions) => {
+                for assumption in assumptions {
+                    all_hold &= assumption.holds();
+                    match assumption.violation.as_deref() {
+                        None => println!("  ok      {}", assumption.description),
+                        Some("") => println!("  FAILED  {}", assumption.description),
+                        Some(violation) => {
+                            println!("  FAILED  {}: {violation}", assumption.description)
+                        }
+                    }
+                }
+            }
+            Err(err) => {
+                all_hold = false;
+                println!("  Could not check the input: {err:#}");
+            }
+        }
+    }
+
+    Ok(exit_code(all_hold))
+}
+
+fn extract(args: ExtractArgs) -> Result<()> {
+    if args.year != YEAR {
+        bail!("No solutions for year {}", args.year);
+    }
+    let day = registry::find(args.day).ok_or(anyhow!("No solution for day {}", args.day))?;
+
+    let html = fs::read_to_string(&args.page)
+        .with_context(|| format!("Could not read {}", args.page.display()))?;
+    runner::capture_panics();
+    let choices = extract::choose(&day, &extract::parse_page(&html)?);
+    if choices.is_empty() {
+        bail!("No highlighted answer in {}", args.page.display());
+    }
+    for choice in choices.iter().filter(|choice| !choice.verified) {
+        println!(
+            "Could not find the answer to part {} ({}) from any example with the default \
+             parameters, check its fixture",
+            choice.part, choice.answer
+        );
+    }
+
+    let workspace = scaffold::workspace_dir();
+    for path in extract::write(&day.fixtures(), &extract::fixtures(&choices), args.force)? {
+        let path = path.strip_prefix(&workspace).unwrap_or(&path);
+        println!("Wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn leaderboard(args: LeaderboardArgs) -> Result<()> {
+    let path = match (&args.file, args.id) {
+        (Some(path), _) => path.clone(),
+        (None, Some(id)) => {
+            let mut inputs = InputLocator::from_env();
+            if let Some(dir) = &args.inputs {
+                inputs = inputs.with_inputs_dir(dir);
+            }
+            Fetcher::new(&Config::load(&inputs)?)?.ensure(inputs.inputs_dir(), args.year, id)?
+        }
+        (None, None) => unreachable!("Clap requires one of them"),
+    };
+
+    let leaderboard = Leaderboard::load(&path)?;
+    if leaderboard.year()? != args.year {
+        bail!(
+            "{} is the leaderboard of {}",
+            path.display(),
+            leaderboard.event
+        );
+    }
+    println!("{}", leaderboard.standings());
+    if let Some(day) = args.day {
+        println!("\nDay {day}\n{}", leaderboard.day(day)?);
+    }
+    Ok(())
+}
+
+fn list() {
+    for day in registry::days() {
+        println!("{YEAR} day {:02}: {}", day.day, day.title);
+        for param in day.params {
+            println!(
+                "    --param {}={}  {}",
+                param.name, param.default, param.description
+            );
+        }
+    }
+}
+
+/// Solves the selected days, returning their reports and where their inputs were looked up
+fn solve(args: &RunArgs) -> Result<(Vec<Report>, InputLocator)> {
+    if args.year != YEAR {
+        bail!("No solutions for year {}", args.year);
+    }
+
+    let selected = if args.all {
+        registry::days()
+    } else {
+        args.days
+            .iter()
+            .map(|&day| registry::find(day).ok_or(anyhow!("No solution for day {day}")))
+            .collect::<Result<Vec<_>>>()?
+    };
+
+    let parts = match args.part {
+        Some(1) => Parts::One,
+        Some(2) => Parts::Two,
+        _ => Parts::Both,
+    };
+
+    /* Days solved at the same time would count each other's allocations */
+    let jobs = match args.jobs {
+        _ if cfg!(feature = "alloc-stats") => 1,
+        Some(jobs) => jobs.into(),
+        None => thread::available_parallelism().map_or(1, usize::from),
+    };
+
+    runner::capture_panics();
+    if args.example {
+        let mut tasks = Vec::new();
+        for &day in &selected {
+            for example in day.examples()? {
+                tasks.push(move || runner::run_example(&day, &example, parts));
+            }
+        }
+        return Ok((
+            sorted(runner::in_parallel(jobs, tasks)),
+            InputLocator::from_env(),
+        ));
+    }
+
+    /* Each override only applies to the days that declare it, but it has to mean something to at
+     * least one of them, otherwise it's most likely a typo. */
+    for (name, _) in &args.params {
+        if !selected
+            .iter()
+            .any(|day| day.params.iter().any(|param| param.name == name))
+        {
+            bail!("None of the selected days has a parameter named `{name}`");
+        }
+    }
+
+    let params = selected
+        .iter()
+        .map(|day| {
+            let mut params = day.default_params();
+            for (name, value) in &args.params {
+                if day.params.iter().any(|param| param.name == name) {
+                    params.set(name, value)?;
+                }
+            }
+            Ok(params)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut inputs = InputLocator::from_env();
+    if let Some(input) = &args.input {
+        inputs = inputs.with_explicit(Input::from_arg(input));
+    }
+    if let Some(dir) = &args.inputs {
+        inputs = inputs.with_inputs_dir(dir);
+    }
+
+    if inputs.explicit().is_some() && selected.len() > 1 {
+        bail!("A single input file (--input or {INPUT_VAR}) can only be used with one day");
+    }
+    if inputs.explicit().is_none() {
+        download_missing(&selected, &inputs, args.format)?;
+    }
+
+    let tasks = selected
+        .iter()
+        .zip(&params)
+        .map(|(day, params)| || runner::run_with_inputs(day, &inputs, parts, params))
+        .collect();
+    let reports = sorted(runner::in_parallel(jobs, tasks));
+
+    Ok((reports, inputs))
+}
+
+/// Downloads the inputs that aren't in the inputs directory yet, if there is a session cookie to do
+/// it with. Days whose input can't be downloaded fail like any other day with a missing input.
+fn download_missing(days: &[Day], inputs: &InputLocator, format: Format) -> Result<()> {
+    let missing = days
+        .iter()
+        .filter(|day| !inputs.path(YEAR, day.day).is_file())
+        .collect::<Vec<_>>();
+    if missing.is_empty() {
+        return Ok(());
+    }
+
+    let config = Config::load(inputs)?;
+    if config.session.is_none() {
+        return Ok(());
+    }
+    let downloader = Downloader::new(&config)?;
+    for day in missing {
+        match downloader.ensure(inputs, YEAR, day.day) {
+            Ok(path) => note(format, &format!("Downloaded {}", path.display())),
+            Err(err) => note(format, &format!("{err:#}")),
+        }
+    }
+    Ok(())
+}
+
+/// Orders the reports by day, keeping the examples of a day in their order
+fn sorted(mut reports: Vec<Report>) -> Vec<Report> {
+    reports.sort_by_key(|report| report.day);
+    reports
+}
+
+fn exit_code(success: bool) -> ExitCode {
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn ledger_path(args: &LedgerArgs, inputs: &InputLocator) -> PathBuf {
+    args.ledger
+        .clone()
+        .unwrap_or_else(|| inputs.inputs_dir().join("answers.txt"))
+}
+
+fn print_reports(format: Format, reports: &[Report]) {
+    match format {
+        Format::Table => println!("{}", summary::table(reports)),
+        Format::Json => println!("{}", output::json_lines(&output::records(reports))),
+        Format::Tsv => println!("{}", output::tsv(&output::records(reports))),
+    }
+}
+
+/// Prints a message for humans, keeping stdout clean for the machine readable formats
+fn note(format: Format, message: &str) {
+    match format {
+        Format::Table => println!("{message}"),
+        Format::Json | Format::Tsv => eprintln!("{message}"),
+    }
+}
+
+fn run(args: RunArgs) -> Result<ExitCode> {
+    let start = Instant::now();
+    let (reports, _) = solve(&args)?;
+    let elapsed = start.elapsed();
+    print_reports(args.format, &reports);
+    /* Days run side by side, so this is usually less than the total of the table */
+    note(args.format, &format!("Wall clock: {elapsed:.2?}"));
+
+    Ok(exit_code(!reports.iter().any(Report::failed)))
+}
+
+fn record(args: LedgerArgs) -> Result<ExitCode> {
+    if args.run.example {
+        bail!("The ledger only holds answers to the real inputs, the examples have their own");
+    }
+    let (reports, inputs) = solve(&args.run)?;
+    let format = args.run.format;
+    print_reports(format, &reports);
+
+    let path = ledger_path(&args, &inputs);
+    let mut ledger = Ledger::load(&path)?;
+    let recorded = ledger.record_reports(YEAR, &reports);
+    ledger.save(&path)?;
+    note(
+        format,
+        &format!("\nRecorded {recorded} answers in {}", path.display()),
+    );
+
+    Ok(exit_code(!reports.iter().any(Report::failed)))
+}
+
+fn verify(args: LedgerArgs) -> Result<ExitCode> {
+    if args.run.example {
+        bail!("The ledger only holds answers to the real inputs, use `aoc run --example` instead");
+    }
+    let (reports, inputs) = solve(&args.run)?;
+    let format = args.run.format;
+    print_reports(format, &reports);
+
+    let path = ledger_path(&args, &inputs);
+    let ledger = Ledger::load(&path)?;
+
+    let mismatches = ledger.mismatches(YEAR, &reports);
+    if !mismatches.is_empty() {
+        note(format, &format!("\nMismatches with {}:", path.display()));
+        for mismatch in &mismatches {
+            note(format, &format!("  {mismatch}"));
+        }
+    }
+
+    let unrecorded = ledger.unrecorded(YEAR, &reports);
+    if !unrecorded.is_empty() {
+        note(format, &format!("\nNot in {} yet:", path.display()));
+        for (day, part) in unrecorded {
+            note(format, &format!("  day {day:02} part {part}"));
+        }
+    }
+
+    Ok(exit_code(
+        mismatches.is_empty() && !reports.iter().any(Report::failed),
+    ))
+}
@@ -0,0 +1,162 @@
+use crate::runner::{Outcome, Report};
+use clap::ValueEnum;
+use common::{Measurement, YEAR};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+/// How reports are printed on stdout
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// A table for humans
+    #[default]
+    Table,
+    /// One JSON object per part
+    Json,
+    /// One tab separated line per part, after a header
+    Tsv,
+}
+
+/// The result of one part. Skipped parts have none.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// The example that was solved instead of the real input
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub example: Option<String>,
+    pub answer: Option<String>,
+    /// In seconds
+    pub duration: f64,
+    pub status: Status,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Solved,
+    Failed,
+}
+
+impl Status {
+    fn as_str(self) -> &'static str {
+        match self {
+            Status::Solved => "solved",
+            Status::Failed => "failed",
+        }
+    }
+}
+
+pub fn records(reports: &[Report]) -> Vec<Record> {
+    reports
+        .iter()
+        .flat_map(|report| {
+            let measurements = [report.measurements.part_one, report.measurements.part_two];
+            report.outcomes().into_iter().zip(measurements).filter_map(
+                |((part, outcome), measurement)| record(report, part, outcome, measurement),
+            )
+        })
+        .collect()
+}
+
+fn record(
+    report: &Report,
+    part: u8,
+    outcome: &Outcome,
+    measurement: Measurement,
+) -> Option<Record> {
+    let (answer, status, error) = match outcome {
+        Outcome::Solved(answer) => (Some(answer.clone()), Status::Solved, None),
+        Outcome::Failed(message) => (None, Status::Failed, Some(message.clone())),
+        Outcome::Skipped => return None,
+    };
+
+    Some(Record {
+        year: YEAR,
+        day: report.day,
+        part,
+        example: report.example.clone(),
+        answer,
+        duration: measurement.elapsed.as_secs_f64(),
+        status,
+        error,
+    })
+}
+
+pub fn json_lines(records: &[Record]) -> String {
+    records
+        .iter()
+        .map(|record| serde_json::to_string(record).expect("Records should serialize to JSON"))
+        .join("\n")
+}
+
+pub fn tsv(records: &[Record]) -> String {
+    /* Tabs and newlines can't be escaped in TSV, so they are flattened into spaces. */
+    let field = |text: &str| text.replace(['\t', '\n', '\r'], " ");
+
+    let header = "year\tday\tpart\texample\tanswer\tduration\tstatus\terror".to_owned();
+    let lines = records.iter().map(|record| {
+        [
+            record.year.to_string(),
+            record.day.to_string(),
+            record.part.to_string(),
+            field(record.example.as_deref().unwrap_or_default()),
+            field(record.answer.as_deref().unwrap_or_default()),
+            record.duration.to_string(),
+            record.status.as_str().to_owned(),
+            field(record.error.as_deref().unwrap_or_default()),
+        ]
+        .join("\t")
+    });
+
+    std::iter::once(header).chain(lines).join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::Measurements;
+    use indoc::indoc;
+    use std::time::Duration;
+
+    #[test]
+    fn test_records() {
+        let reports = [Report {
+            day: 5,
+            example: None,
+            part_one: Outcome::Solved("35".to_owned()),
+            part_two: Outcome::Failed("panicked:\tnot yet implemented".to_owned()),
+            measurements: Measurements {
+                part_one: Measurement {
+                    elapsed: Duration::from_millis(250),
+                    allocations: None,
+                },
+                ..Measurements::default()
+            },
+        }];
+        let records = records(&reports);
+
+        let expected = indoc! {r#"
+            {"year":2023,"day":5,"part":1,"answer":"35","duration":0.25,"status":"solved","error":null}
+            {"year":2023,"day":5,"part":2,"answer":null,"duration":0.0,"status":"failed","error":"panicked:\tnot yet implemented"}"#};
+        assert_eq!(json_lines(&records), expected);
+
+        let expected = indoc! {"
+            year\tday\tpart\texample\tanswer\tduration\tstatus\terror
+            2023\t5\t1\t\t35\t0.25\tsolved\t
+            2023\t5\t2\t\t\t0\tfailed\tpanicked: not yet implemented"};
+        assert_eq!(tsv(&records), expected);
+
+        let reports = [Report {
+            example: Some("example-2".to_owned()),
+            ..reports.into_iter().next().unwrap()
+        }];
+        assert_eq!(
+            json_lines(&super::records(&reports)).lines().next(),
+            Some(
+                r#"{"year":2023,"day":5,"part":1,"example":"example-2","answer":"35","duration":0.25,"status":"solved","error":null}"#
+            )
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use crate::scaffold;
+use anyhow::{anyhow, Context, Result};
+use common::{Assumption, Example, Param, Params, Rng, Solution};
+use std::{any::Any, path::PathBuf};
+
+/// A parsed puzzle input, whose concrete type is only known to the day that produced it
+pub type Parsed = Box<dyn Any>;
+
+/// A type-erased `Solution`, so that days with different inputs and answers fit in one list.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    pub title: &'static str,
+    pub params: &'static [Param],
+    pub parse: fn(&str) -> Result<Parsed>,
+    pub part_one: fn(&Parsed, &Params) -> Result<String>,
+    pub part_two: fn(&Parsed, &Params) -> Result<String>,
+    pub generate: fn(&mut Rng, usize) -> Option<String>,
+    pub assumptions: fn(&Parsed) -> Vec<Assumption>,
+}
+
+impl Day {
+    pub fn of<S>() -> Self
+    where
+        S: Solution,
+        S::Input: 'static,
+    {
+        Self {
+            day: S::DAY,
+            title: S::TITLE,
+            params: S::PARAMS,
+            parse: |input| Ok(Box::new(common::parse_input::<S>(input)?)),
+            part_one: |input, params| Ok(S::part_one(input_of::<S>(input), params)?.to_string()),
+            part_two: |input, params| Ok(S::part_two(input_of::<S>(input), params)?.to_string()),
+            generate: S::generate,
+            assumptions: |input| S::assumptions(input_of::<S>(input)),
+        }
+    }
+
+    pub fn default_params(&self) -> Params {
+        Params::defaults(self.params)
+    }
+
+    /// Where the puzzle's examples and their answers are kept, next to the day's sources
+    pub fn fixtures(&self) -> PathBuf {
+        scaffold::workspace_dir()
+            .join(format!("day-{:02}", self.day))
+            .join("fixtures")
+    }
+
+    /// The puzzle's main example input
+    pub fn example(&self) -> PathBuf {
+        self.fixtures().join("example.in")
+    }
+
+    pub fn examples(&self) -> Result<Vec<Example>> {
+        Example::all_in(&self.fixtures())
+    }
+
+    pub fn can_generate(&self) -> bool {
+        (self.generate)(&mut Rng::new(0), 1).is_some()
+    }
+
+    /// A made up input of about `size` lines or rows, always the same for a given seed and size.
+    /// Fails if the day can't make one up, or if it doesn't parse it back.
+    pub fn generate_input(&self, seed: u64, size: usize) -> Result<String> {
+        let input = (self.generate)(&mut Rng::new(seed), size)
+            .ok_or(anyhow!("Day {} can't generate inputs", self.day))?;
+        (self.parse)(&input).with_context(|| {
+            format!(
+                "Day {} generated an input it can't parse (seed {seed}, size {size})",
+                self.day
+            )
+        })?;
+        Ok(input)
+    }
+
+    /// Which of the day's assumptions `input` lives up to
+    pub fn check_input(&self, input: &str) -> Result<Vec<Assumption>> {
+        let parsed = (self.parse)(input)?;
+        Ok((self.assumptions)(&parsed))
+    }
+}
+
+fn input_of<S>(input: &Parsed) -> &S::Input
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    input
+        .downcast_ref()
+        .expect("Parsed input should come from the same day")
+}
+
+pub fn days() -> Vec<Day> {
+    vec![
+        Day::of::<day_01::Day01>(),
+        Day::of::<day_02::Day02>(),
+        Day::of::<day_03::Day03>(),
+        Day::of::<day_04::Day04>(),
+        Day::of::<day_05::Day05>(),
+        Day::of::<day_06::Day06>(),
+        Day::of::<day_07::Day07>(),
+        Day::of::<day_08::Day08>(),
+        Day::of::<day_09::Day09>(),
+        Day::of::<day_10::Day10>(),
+        Day::of::<day_11::Day11>(),
+        Day::of::<day_12::Day12>(),
+        Day::of::<day_13::Day13>(),
+        Day::of::<day_14::Day14>(),
+        Day::of::<day_15::Day15>(),
+        Day::of::<day_16::Day16>(),
+        Day::of::<day_17::Day17>(),
+        Day::of::<day_18::Day18>(),
+        Day::of::<day_19::Day19>(),
+        Day::of::<day_20::Day20>(),
+        Day::of::<day_21::Day21>(),
+    ]
+}
+
+pub fn find(day: u8) -> Option<Day> {
+    days().into_iter().find(|d| d.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_are_registered_in_order() {
+        let days = days().iter().map(|d| d.day).collect::<Vec<_>>();
+        assert_eq!(days, (1..=days.len() as u8).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_generated_inputs_are_solved() -> Result<()> {
+        let generating = days()
+            .into_iter()
+            .filter(Day::can_generate)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            generating.iter().map(|day| day.day).collect::<Vec<_>>(),
+            [1, 2, 3, 4, 5, 6, 7, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19]
+        );
+
+        for day in generating {
+            for seed in 0..5 {
+                let input = day.generate_input(seed, 20)?;
+                assert_eq!(input, day.generate_input(seed, 20)?);
+
+                let parsed = (day.parse)(&input)?;
+                let params = day.default_params();
+                /* Day 05 has no part two at the moment */
+                let parts = match day.day {
+                    5 => vec![day.part_one],
+                    _ => vec![day.part_one, day.part_two],
+                };
+                for part in parts {
+                    part(&parsed, &params)
+                        .with_context(|| format!("Day {} seed {seed}:\n{input}", day.day))?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,348 @@
+use crate::registry::{Day, Parsed};
+use common::{measure, Example, InputLocator, Measurement, Params, YEAR};
+use std::{
+    cell::RefCell,
+    panic::{self, PanicHookInfo},
+    sync::Mutex,
+    thread,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Solved(String),
+    Failed(String),
+    Skipped,
+}
+
+impl Outcome {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Outcome::Failed(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Parts {
+    Both,
+    One,
+    Two,
+}
+
+impl Parts {
+    pub fn includes(self, part: u8) -> bool {
+        matches!(
+            (self, part),
+            (Parts::Both, _) | (Parts::One, 1) | (Parts::Two, 2)
+        )
+    }
+
+    /// The parts selected by both `self` and `other`, if any
+    fn intersect(self, other: Parts) -> Option<Parts> {
+        match (
+            self.includes(1) && other.includes(1),
+            self.includes(2) && other.includes(2),
+        ) {
+            (true, true) => Some(Parts::Both),
+            (true, false) => Some(Parts::One),
+            (false, true) => Some(Parts::Two),
+            (false, false) => None,
+        }
+    }
+}
+
+/// What parsing and each part cost, skipped parts being left at zero
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Measurements {
+    pub parse: Measurement,
+    pub part_one: Measurement,
+    pub part_two: Measurement,
+}
+
+impl Measurements {
+    pub fn total(&self) -> Measurement {
+        self.parse + self.part_one + self.part_two
+    }
+}
+
+#[derive(Debug)]
+pub struct Report {
+    pub day: u8,
+    /// The name of the example that was solved instead of the real input, if any
+    pub example: Option<String>,
+    pub part_one: Outcome,
+    pub part_two: Outcome,
+    pub measurements: Measurements,
+}
+
+impl Report {
+    /// The outcome of each part, along with its number
+    pub fn outcomes(&self) -> [(u8, &Outcome); 2] {
+        [(1, &self.part_one), (2, &self.part_two)]
+    }
+
+    pub fn failed(&self) -> bool {
+        self.part_one.is_failure() || self.part_two.is_failure()
+    }
+}
+
+thread_local! {
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/* The default hook would print every panic (including `todo!()`s) in the middle of the summary.
+ * Instead, remember the message so that it can be reported alongside the failing part. */
+pub fn capture_panics() {
+    panic::set_hook(Box::new(|info: &PanicHookInfo| {
+        let payload = info.payload();
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "Box<dyn Any>".to_owned());
+
+        let message = match info.location() {
+            Some(location) => format!("panicked at {location}: {message}"),
+            None => format!("panicked: {message}"),
+        };
+
+        LAST_PANIC.with(|last| *last.borrow_mut() = Some(message));
+    }));
+}
+
+fn guarded<T>(f: impl FnOnce() -> anyhow::Result<T>) -> Result<T, String> {
+    match panic::catch_unwind(panic::AssertUnwindSafe(f)) {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(err)) => Err(format!("{err:#}")),
+        Err(_) => Err(LAST_PANIC
+            .with(|last| last.borrow_mut().take())
+            .unwrap_or_else(|| "panicked".to_owned())),
+    }
+}
+
+/// Parses `input` once and solves the selected parts from it. If parsing fails, so do both parts.
+pub fn run(day: &Day, input: &str, parts: Parts, params: &Params) -> Report {
+    let (parsed, parse) = measure(|| guarded(|| (day.parse)(input)));
+
+    let solve = |part, solver: fn(&Parsed, &Params) -> anyhow::Result<String>| {
+        if !parts.includes(part) {
+            return (Outcome::Skipped, Measurement::default());
+        }
+
+        match &parsed {
+            Ok(parsed) => match measure(|| guarded(|| solver(parsed, params))) {
+                (Ok(answer), measurement) => (Outcome::Solved(answer), measurement),
+                (Err(message), measurement) => (Outcome::Failed(message), measurement),
+            },
+            Err(message) => (
+                Outcome::Failed(format!("could not parse input: {message}")),
+                Measurement::default(),
+            ),
+        }
+    };
+
+    let (part_one, part_one_measurement) = solve(1, day.part_one);
+    let (part_two, part_two_measurement) = solve(2, day.part_two);
+
+    Report {
+        day: day.day,
+        example: None,
+        part_one,
+        part_two,
+        measurements: Measurements {
+            parse,
+            part_one: part_one_measurement,
+            part_two: part_two_measurement,
+        },
+    }
+}
+
+pub fn run_with_inputs(day: &Day, inputs: &InputLocator, parts: Parts, params: &Params) -> Report {
+    match inputs.read(YEAR, day.day) {
+        Ok(input) => run(day, &input, parts, params),
+        Err(err) => failed(day, format!("{err:#}")),
+    }
+}
+
+/// Solves the selected parts of an example that have an expected answer, failing those that don't
+/// match it
+pub fn run_example(day: &Day, example: &Example, parts: Parts) -> Report {
+    let answered = match (example.part_one.is_some(), example.part_two.is_some()) {
+        (true, true) => Some(Parts::Both),
+        (true, false) => Some(Parts::One),
+        (false, true) => Some(Parts::Two),
+        (false, false) => None,
+    };
+
+    let mut report = match (
+        example.params(day.params),
+        answered.and_then(|answered| parts.intersect(answered)),
+    ) {
+        (Err(err), _) => failed(day, format!("{err:#}")),
+        (Ok(params), Some(parts)) => run(day, &example.input, parts, &params),
+        (Ok(_), None) => Report {
+            day: day.day,
+            example: None,
+            part_one: Outcome::Skipped,
+            part_two: Outcome::Skipped,
+            measurements: Measurements::default(),
+        },
+    };
+
+    for (part, outcome) in [(1, &mut report.part_one), (2, &mut report.part_two)] {
+        if let Outcome::Solved(answer) = outcome {
+            if let Err(err) = example.compare(part, answer) {
+                *outcome = Outcome::Failed(err.to_string());
+            }
+        }
+    }
+
+    report.example = Some(example.name.clone());
+    report
+}
+
+/// Runs the tasks on up to `jobs` threads, each one taking the next task as soon as it's done with
+/// the previous one. The results come back in the order of the tasks.
+pub fn in_parallel<T, F>(jobs: usize, tasks: Vec<F>) -> Vec<T>
+where
+    T: Send,
+    F: FnOnce() -> T + Send,
+{
+    let count = tasks.len();
+    let tasks = Mutex::new(tasks.into_iter().enumerate());
+    let results = Mutex::new(Vec::with_capacity(count));
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, count.max(1)) {
+            scope.spawn(|| loop {
+                let Some((index, task)) = tasks
+                    .lock()
+                    .expect("Nothing panics while holding the lock")
+                    .next()
+                else {
+                    break;
+                };
+                let result = task();
+                results
+                    .lock()
+                    .expect("Nothing panics while holding the lock")
+                    .push((index, result));
+            });
+        }
+    });
+
+    let mut results = results
+        .into_inner()
+        .expect("Nothing panics while holding the lock");
+    results.sort_by_key(|&(index, _)| index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+fn failed(day: &Day, message: String) -> Report {
+    let failure = Outcome::Failed(message);
+    Report {
+        day: day.day,
+        example: None,
+        part_one: failure.clone(),
+        part_two: failure,
+        measurements: Measurements::default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+
+    const FLAKY_DAY: Day = Day {
+        day: 99,
+        title: "Flaky",
+        params: &[],
+        parse: |input| Ok(Box::new(input.len())),
+        part_one: |input, _| Ok(input.downcast_ref::<usize>().unwrap().to_string()),
+        part_two: |_, _| todo!("part two"),
+        generate: |_, _| None,
+        assumptions: |_| Vec::new(),
+    };
+
+    #[test]
+    fn test_panicking_part_is_a_failure() {
+        capture_panics();
+        let report = run(&FLAKY_DAY, "abc", Parts::Both, &Params::defaults(&[]));
+
+        assert_eq!(report.part_one, Outcome::Solved("3".to_owned()));
+        match report.part_two {
+            Outcome::Failed(message) => assert!(message.contains("not yet implemented: part two")),
+            outcome => panic!("expected a failure, got {outcome:?}"),
+        }
+    }
+
+    #[test]
+    fn test_error_is_a_failure() {
+        let day = Day {
+            part_one: |_, _| Err(anyhow!("bad input")),
+            ..FLAKY_DAY
+        };
+        let report = run(&day, "", Parts::One, &Params::defaults(&[]));
+
+        assert_eq!(report.part_one, Outcome::Failed("bad input".to_owned()));
+        assert_eq!(report.part_two, Outcome::Skipped);
+        assert!(report.failed());
+    }
+
+    #[test]
+    fn test_parse_error_fails_both_parts() {
+        let day = Day {
+            parse: |_| Err(anyhow!("no digits")),
+            ..FLAKY_DAY
+        };
+        let report = run(&day, "", Parts::Both, &Params::defaults(&[]));
+
+        let failure = Outcome::Failed("could not parse input: no digits".to_owned());
+        assert_eq!(report.part_one, failure);
+        assert_eq!(report.part_two, failure);
+    }
+
+    #[test]
+    fn test_example_answers_are_checked() {
+        let example = Example {
+            name: "short".to_owned(),
+            input: "abc".to_owned(),
+            params: Vec::new(),
+            part_one: Some("4".to_owned()),
+            part_two: None,
+        };
+        let report = run_example(&FLAKY_DAY, &example, Parts::Both);
+
+        assert_eq!(report.example.as_deref(), Some("short"));
+        assert_eq!(
+            report.part_one,
+            Outcome::Failed("Example `short` part 1: expected `4`, got `3`".to_owned())
+        );
+        assert_eq!(report.part_two, Outcome::Skipped);
+        assert_eq!(
+            run_example(&FLAKY_DAY, &example, Parts::Two).part_one,
+            Outcome::Skipped
+        );
+    }
+
+    #[test]
+    fn test_in_parallel() {
+        capture_panics();
+        let inputs = (0..20).map(|len| "x".repeat(len)).collect::<Vec<_>>();
+        let tasks = inputs
+            .iter()
+            .map(|input| || run(&FLAKY_DAY, input, Parts::Both, &Params::defaults(&[])))
+            .collect();
+
+        let reports = in_parallel(4, tasks);
+        for (len, report) in reports.iter().enumerate() {
+            assert_eq!(report.part_one, Outcome::Solved(len.to_string()));
+            /* Each thread reports its own panics */
+            match &report.part_two {
+                Outcome::Failed(message) => assert!(message.contains("part two")),
+                outcome => panic!("expected a failure, got {outcome:?}"),
+            }
+        }
+
+        assert!(in_parallel(4, Vec::<fn() -> u8>::new()).is_empty());
+    }
+}
@@ -0,0 +1,253 @@
+use anyhow::{anyhow, bail, Context, Result};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The workspace the `aoc` crate belongs to, where new days are created
+pub fn workspace_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("The aoc crate should be inside the workspace")
+        .to_owned()
+}
+
+/// Creates the `day-NN` crate with a stub solution, an empty example and its test, then registers it
+/// with the runner. Returns the files that were created or changed.
+pub fn new_day(workspace: &Path, day: u8, title: &str) -> Result<Vec<PathBuf>> {
+    let name = format!("day-{day:02}");
+    let dir = workspace.join(&name);
+    if dir.exists() {
+        bail!("{} already exists", dir.display());
+    }
+
+    let aoc_manifest = workspace.join("aoc").join("Cargo.toml");
+    let registry = workspace.join("aoc").join("src").join("registry.rs");
+
+    /* Work out both edits before touching anything, so that a failure leaves the workspace as it was */
+    let manifest_source = read(&aoc_manifest)?;
+    let registry_source = read(&registry)?;
+    let manifest_source = add_dependency(&manifest_source, day)
+        .with_context(|| format!("Could not add {name} to {}", aoc_manifest.display()))?;
+    let registry_source = register(&registry_source, day)
+        .with_context(|| format!("Could not register {name} in {}", registry.display()))?;
+
+    let files = [
+        (dir.join("Cargo.toml"), cargo_toml(day)),
+        (dir.join("src").join("lib.rs"), lib_rs(day, title)),
+        (dir.join("src").join("main.rs"), main_rs(day)),
+        (dir.join("fixtures").join("example.in"), String::new()),
+        (dir.join("fixtures").join("example.answers"), answers()),
+        (aoc_manifest, manifest_source),
+        (registry, registry_source),
+    ];
+
+    for (path, contents) in &files {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, contents).with_context(|| format!("Could not write {}", path.display()))?;
+    }
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("Could not read {}", path.display()))
+}
+
+fn cargo_toml(day: u8) -> String {
+    format!(
+        r#"[package]
+name = "day-{day:02}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+anyhow.workspace = true
+common.workspace = true
+rstest.workspace = true
+itertools.workspace = true
+"#
+    )
+}
+
+fn lib_rs(day: u8, title: &str) -> String {
+    let title = title.replace('\\', "\\\\").replace('"', "\\\"");
+    format!(
+        r#"use anyhow::Result;
+use common::{{Params, Solution}};
+
+pub struct Day{day:02};
+
+impl Solution for Day{day:02} {{
+    const DAY: u8 = {day};
+    const TITLE: &'static str = "{title}";
+
+    type Input = Vec<String>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {{
+        Ok(input.lines().map(str::to_owned).collect())
+    }}
+
+    fn part_one(_input: &Self::Input, _: &Params) -> Result<usize> {{
+        todo!("part one")
+    }}
+
+    fn part_two(_input: &Self::Input, _: &Params) -> Result<usize> {{
+        todo!("part two")
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+    use common::Example;
+    use rstest::*;
+    use std::path::PathBuf;
+
+    #[rstest]
+    fn test_examples(#[files("fixtures/*.in")] path: PathBuf) -> Result<()> {{
+        Example::load(&path)?.check::<Day{day:02}>()
+    }}
+}}
+"#
+    )
+}
+
+fn main_rs(day: u8) -> String {
+    format!(
+        r#"fn main() -> anyhow::Result<()> {{
+    common::main::<day_{day:02}::Day{day:02}>()
+}}
+"#
+    )
+}
+
+fn answers() -> String {
+    "# Paste the example from the puzzle in example.in, then its answers here, like `part 1 = 42`\n"
+        .to_owned()
+}
+
+/// Inserts `line` among the lines that start with `prefix`, in order: before the first one that
+/// sorts after it, or after the last one
+fn insert_sorted(source: &str, prefix: &str, line: &str) -> Result<String> {
+    let mut lines = source.lines().collect::<Vec<_>>();
+    let matching = |l: &&str| l.trim_start().starts_with(prefix);
+    let last = lines
+        .iter()
+        .rposition(matching)
+        .ok_or(anyhow!("No line starting with `{prefix}`"))?;
+
+    if lines.iter().any(|l| l.trim() == line.trim()) {
+        bail!("`{}` is already there", line.trim());
+    }
+
+    let (neighbour, index) = match lines
+        .iter()
+        .position(|l| matching(l) && l.trim() > line.trim())
+    {
+        Some(next) => (next, next),
+        None => (last, last + 1),
+    };
+    let indent = &lines[neighbour][..lines[neighbour].len() - lines[neighbour].trim_start().len()];
+    let line = format!("{indent}{}", line.trim());
+    lines.insert(index, &line);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the day as a dependency of the `aoc` crate
+fn add_dependency(manifest: &str, day: u8) -> Result<String> {
+    insert_sorted(
+        manifest,
+        "day-",
+        &format!(r#"day-{day:02} = {{ path = "../day-{day:02}" }}"#),
+    )
+}
+
+/// Adds the day to the list in `registry::days`
+fn register(registry: &str, day: u8) -> Result<String> {
+    insert_sorted(
+        registry,
+        "Day::of::<day_",
+        &format!("Day::of::<day_{day:02}::Day{day:02}>(),"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_register() -> Result<()> {
+        let registry = indoc! {"
+            pub fn days() -> Vec<Day> {
+                vec![
+                    Day::of::<day_01::Day01>(),
+                    Day::of::<day_02::Day02>(),
+                ]
+            }
+        "};
+
+        assert_eq!(
+            register(registry, 3)?,
+            indoc! {"
+                pub fn days() -> Vec<Day> {
+                    vec![
+                        Day::of::<day_01::Day01>(),
+                        Day::of::<day_02::Day02>(),
+                        Day::of::<day_03::Day03>(),
+                    ]
+                }
+            "}
+        );
+        assert!(register(registry, 2).is_err());
+
+        let registry = registry.replace("day_02::Day02", "day_04::Day04");
+        assert_eq!(
+            register(&registry, 3)?,
+            indoc! {"
+                pub fn days() -> Vec<Day> {
+                    vec![
+                        Day::of::<day_01::Day01>(),
+                        Day::of::<day_03::Day03>(),
+                        Day::of::<day_04::Day04>(),
+                    ]
+                }
+            "}
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_dependency() -> Result<()> {
+        let manifest = indoc! {r#"
+            [dependencies]
+            anyhow.workspace = true
+            day-01 = { path = "../day-01" }
+
+            [dev-dependencies]
+            indoc.workspace = true
+        "#};
+
+        assert_eq!(
+            add_dependency(manifest, 2)?,
+            indoc! {r#"
+                [dependencies]
+                anyhow.workspace = true
+                day-01 = { path = "../day-01" }
+                day-02 = { path = "../day-02" }
+
+                [dev-dependencies]
+                indoc.workspace = true
+            "#}
+        );
+        Ok(())
+    }
+}
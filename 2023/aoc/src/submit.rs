@@ -0,0 +1,493 @@
+use crate::download::{self, Config};
+use anyhow::{anyhow, bail, Context, Result};
+use std::{
+    fmt::Display,
+    fs, io,
+    path::Path,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// What the site made of a submitted answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint
+    Wrong,
+    /// Submitted too soon after the previous answer, so it wasn't checked
+    TooSoon,
+    /// The part was already solved, or isn't unlocked yet
+    WrongLevel,
+}
+
+impl Verdict {
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Wrong => "wrong",
+            Verdict::TooSoon => "too-soon",
+            Verdict::WrongLevel => "wrong-level",
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            Verdict::Correct,
+            Verdict::TooHigh,
+            Verdict::TooLow,
+            Verdict::Wrong,
+            Verdict::TooSoon,
+            Verdict::WrongLevel,
+        ]
+        .into_iter()
+        .find(|verdict| verdict.as_str() == s)
+        .ok_or(anyhow!("Unknown verdict `{s}`"))
+    }
+}
+
+/// The verdict found in the page the site answers a submission with, along with how long to wait
+/// before submitting again, if it says so
+pub fn parse_response(page: &str) -> Result<(Verdict, Option<Duration>)> {
+    /* Only the `<article>` holds the message, the rest of the page is navigation */
+    let article = page
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(page, |(article, _)| article);
+    let text = strip_tags(article);
+
+    let verdict = if text.contains("That's the right answer") {
+        Verdict::Correct
+    } else if text.contains("That's not the right answer") {
+        if text.contains("too high") {
+            Verdict::TooHigh
+        } else if text.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }
+    } else if text.contains("You gave an answer too recently") {
+        Verdict::TooSoon
+    } else if text.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        bail!("Unexpected response: {}", text.trim());
+    };
+
+    Ok((verdict, wait(&text)))
+}
+
+/// The text of `html`, without its tags and with its entities decoded
+pub(crate) fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for ch in html.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(ch),
+            _ => {}
+        }
+    }
+    /* `&amp;` last, so that an escaped entity like `&amp;lt;` stays as written */
+    text.replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+/// The delay in `You have 1m 30s left to wait` or `Please wait 5 minutes before trying again`
+fn wait(text: &str) -> Option<Duration> {
+    if let Some((_, rest)) = text.split_once("You have ") {
+        let (left, _) = rest.split_once(" left to wait")?;
+        return left
+            .split_whitespace()
+            .map(|amount| {
+                let (number, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+                let number = number.parse::<u64>().ok()?;
+                match unit {
+                    "h" => Some(number * 3600),
+                    "m" => Some(number * 60),
+                    "s" => Some(number),
+                    _ => None,
+                }
+            })
+            .sum::<Option<u64>>()
+            .map(Duration::from_secs);
+    }
+
+    let (_, rest) = text.split_once("wait ")?;
+    let mut words = rest.split_whitespace();
+    let minutes = match words.next()? {
+        "one" => 1,
+        number => number.parse().ok()?,
+    };
+    words
+        .next()?
+        .starts_with("minute")
+        .then(|| Duration::from_secs(minutes * 60))
+}
+
+/// One submitted answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    /// Seconds since the Unix epoch
+    pub time: u64,
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub verdict: Verdict,
+    /// How long the site asked to wait before submitting again
+    pub wait: Duration,
+    pub answer: String,
+}
+
+/// Every answer submitted so far, to avoid submitting answers that are already known to be wrong
+/// and to respect the delays the site asks for.
+///
+/// Like the ledger, it is plain text with one `time year day part verdict wait answer` line per
+/// submission.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct History {
+    submissions: Vec<Submission>,
+}
+
+impl History {
+    /// Loads the history at `path`, which is empty if the file doesn't exist yet
+    pub fn load(path: &Path) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => contents
+                .parse()
+                .with_context(|| format!("Invalid history {}", path.display())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err).with_context(|| format!("Could not read {}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_string())
+            .with_context(|| format!("Could not write {}", path.display()))
+    }
+
+    pub fn record(&mut self, submission: Submission) {
+        self.submissions.push(submission);
+    }
+
+    fn of_part(&self, year: u16, day: u8, part: u8) -> impl Iterator<Item = &Submission> {
+        self.submissions
+            .iter()
+            .filter(move |s| (s.year, s.day, s.part) == (year, day, part))
+    }
+
+    /// Fails if submitting `answer` at `now` is bound to be a waste, or to be refused
+    pub fn check(&self, year: u16, day: u8, part: u8, answer: &str, now: u64) -> Result<()> {
+        let submissions = self.of_part(year, day, part).collect::<Vec<_>>();
+
+        if let Some(correct) = submissions.iter().find(|s| s.verdict == Verdict::Correct) {
+            bail!("Part {part} was already solved with {}", correct.answer);
+        }
+        if let Some(wrong) = submissions
+            .iter()
+            .find(|s| s.verdict.is_wrong() && s.answer == answer)
+        {
+            bail!(
+                "{answer} was already submitted, and it was {}",
+                wrong.verdict
+            );
+        }
+
+        /* The hints bound the answer, when it's a number */
+        if let Ok(number) = answer.parse::<i128>() {
+            let bound = |verdict| {
+                submissions
+                    .iter()
+                    .filter(move |s| s.verdict == verdict)
+                    .filter_map(|s| s.answer.parse::<i128>().ok())
+            };
+            if let Some(high) = bound(Verdict::TooHigh).min().filter(|&high| number >= high) {
+                bail!("{answer} is too high, since {high} already was");
+            }
+            if let Some(low) = bound(Verdict::TooLow).max().filter(|&low| number <= low) {
+                bail!("{answer} is too low, since {low} already was");
+            }
+        }
+
+        /* The site throttles each puzzle on its own */
+        let ready = self
+            .submissions
+            .iter()
+            .filter(|s| (s.year, s.day) == (year, day))
+            .map(|s| s.time + s.wait.as_secs())
+            .max()
+            .unwrap_or(0);
+        if ready > now {
+            bail!(
+                "The site asked to wait another {}s before submitting again",
+                ready - now
+            );
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for History {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut history = Self::default();
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields = line.splitn(7, ' ').collect::<Vec<_>>();
+            let [time, year, day, part, verdict, wait, answer] = fields[..] else {
+                return Err(anyhow!(
+                    "Expected `time year day part verdict wait answer` on line {}: {line}",
+                    i + 1
+                ));
+            };
+
+            history.record(Submission {
+                time: time.parse()?,
+                year: year.parse()?,
+                day: day.parse()?,
+                part: part.parse()?,
+                verdict: verdict.parse()?,
+                wait: Duration::from_secs(wait.parse()?),
+                answer: answer.to_owned(),
+            });
+        }
+
+        Ok(history)
+    }
+}
+
+impl Display for History {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# time year day part verdict wait answer")?;
+        for s in &self.submissions {
+            writeln!(
+                f,
+                "{} {} {:02} {} {} {} {}",
+                s.time,
+                s.year,
+                s.day,
+                s.part,
+                s.verdict,
+                s.wait.as_secs(),
+                s.answer
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Posts answers to the site
+pub struct Submitter {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Submitter {
+    pub fn new(config: &Config) -> Result<Self> {
+        Ok(Self {
+            agent: download::agent(),
+            base_url: config.base_url(),
+            session: config.session()?.to_owned(),
+        })
+    }
+
+    /// Submits `answer` unless the history shows it's pointless, and records the verdict
+    pub fn submit(
+        &self,
+        history: &mut History,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<Submission> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        history.check(year, day, part, answer, now)?;
+
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let page = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|err| anyhow!("Could not submit to {url}: {err}"))?
+            .into_string()
+            .with_context(|| format!("Could not read the response of {url}"))?;
+        let (verdict, wait) = parse_response(&page)?;
+
+        let submission = Submission {
+            time: now,
+            year,
+            day,
+            part,
+            verdict,
+            wait: wait.unwrap_or_default(),
+            answer: answer.to_owned(),
+        };
+        history.record(submission.clone());
+        Ok(submission)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fake_site::FakeSite;
+    use rstest::*;
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main><article><p>{message}</p></article></main></body></html>")
+    }
+
+    #[rstest]
+    #[case(
+        "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.",
+        Verdict::Correct,
+        None
+    )]
+    #[case(
+        "That's not the right answer; your answer is too high.  Please wait one minute before \
+         trying again. <a href=\"/2023/day/14\">[Return to Day 14]</a>",
+        Verdict::TooHigh,
+        Some(60)
+    )]
+    #[case(
+        "That's not the right answer; your answer is too low.  Please wait 5 minutes before \
+         trying again.",
+        Verdict::TooLow,
+        Some(300)
+    )]
+    #[case(
+        "That's not the right answer.  If you're stuck, make sure you're using the full input \
+         data.",
+        Verdict::Wrong,
+        None
+    )]
+    #[case(
+        "You gave an answer too recently; you have to wait after submitting an answer before \
+         trying again.  You have 1m 5s left to wait.",
+        Verdict::TooSoon,
+        Some(65)
+    )]
+    #[case(
+        "You don't seem to be solving the right level.  Did you already complete it?",
+        Verdict::WrongLevel,
+        None
+    )]
+    fn test_parse_response(
+        #[case] message: &str,
+        #[case] verdict: Verdict,
+        #[case] wait: Option<u64>,
+    ) -> Result<()> {
+        assert_eq!(
+            parse_response(&page(message))?,
+            (verdict, wait.map(Duration::from_secs))
+        );
+        Ok(())
+    }
+
+    fn submission(time: u64, part: u8, verdict: Verdict, answer: &str) -> Submission {
+        Submission {
+            time,
+            year: 2023,
+            day: 14,
+            part,
+            verdict,
+            wait: Duration::from_secs(60),
+            answer: answer.to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_check() -> Result<()> {
+        let mut history = History::default();
+        history.record(submission(1000, 2, Verdict::TooHigh, "104533"));
+        history.record(submission(1100, 2, Verdict::TooLow, "96000"));
+        history.record(submission(1200, 2, Verdict::Wrong, "100000"));
+        assert_eq!(history.to_string().parse::<History>()?, history);
+
+        let check = |answer, now| history.check(2023, 14, 2, answer, now);
+        assert!(check("100001", 1300).is_ok());
+        assert!(check("100000", 1300).is_err());
+        assert!(check("104533", 1300).is_err());
+        assert!(check("200000", 1300).is_err());
+        assert!(check("96000", 1300).is_err());
+        assert!(check("10", 1300).is_err());
+        assert!(check("100001", 1230).is_err());
+        assert!(history.check(2023, 14, 1, "104533", 1300).is_ok());
+
+        history.record(submission(1300, 2, Verdict::Correct, "100001"));
+        assert!(history.check(2023, 14, 2, "100002", 2000).is_err());
+        Ok(())
+    }
+
+    /// The site, for which the answers to 2023 day 14 are 1 and 64
+    fn fake_site() -> FakeSite {
+        FakeSite::start(|request| {
+            let message = match (request.url.as_str(), request.body.as_str()) {
+                ("/2023/day/14/answer", "level=1&answer=1" | "level=2&answer=64") => {
+                    "That's the right answer!"
+                }
+                ("/2023/day/14/answer", "level=2&answer=100") => {
+                    "That's not the right answer; your answer is too high."
+                }
+                _ => "Nothing to see here",
+            };
+            (200, page(message))
+        })
+    }
+
+    #[test]
+    fn test_submit() -> Result<()> {
+        let site = fake_site();
+        let submitter = Submitter::new(&Config {
+            session: Some("secret".to_owned()),
+            base_url: site.base_url.clone(),
+        })?;
+        let mut history = History::default();
+
+        let submitted = submitter.submit(&mut history, 2023, 14, 2, "100")?;
+        assert_eq!(submitted.verdict, Verdict::TooHigh);
+
+        /* Refused without asking the site */
+        assert!(submitter.submit(&mut history, 2023, 14, 2, "150").is_err());
+        assert_eq!(site.requests(), 1);
+
+        let submitted = submitter.submit(&mut history, 2023, 14, 2, "64")?;
+        assert_eq!(submitted.verdict, Verdict::Correct);
+        let submitted = submitter.submit(&mut history, 2023, 14, 1, "1")?;
+        assert_eq!(submitted.verdict, Verdict::Correct);
+        assert_eq!(site.requests(), 3);
+        Ok(())
+    }
+}
@@ -0,0 +1,209 @@
+use crate::runner::{Outcome, Report};
+use common::Measurement;
+use itertools::Itertools;
+
+fn cell(outcome: &Outcome) -> String {
+    match outcome {
+        Outcome::Solved(answer) => answer.clone(),
+        Outcome::Failed(_) => "FAILED".to_owned(),
+        Outcome::Skipped => "-".to_owned(),
+    }
+}
+
+fn measurement_cell(outcome: &Outcome, measurement: &Measurement) -> String {
+    match outcome {
+        Outcome::Skipped => "-".to_owned(),
+        _ => measurement.to_string(),
+    }
+}
+
+/// The day of a report, followed by the name of its example if it solved one
+fn day_cell(report: &Report) -> String {
+    match &report.example {
+        Some(example) => format!("{:02} {example}", report.day),
+        None => format!("{:02}", report.day),
+    }
+}
+
+pub fn table(reports: &[Report]) -> String {
+    let header = ["Day", "Part 1", "Part 2", "Parse", "Solve 1", "Solve 2"].map(str::to_owned);
+    let rows = reports
+        .iter()
+        .map(|report| {
+            let measurements = &report.measurements;
+            [
+                day_cell(report),
+                cell(&report.part_one),
+                cell(&report.part_two),
+                measurements.parse.to_string(),
+                measurement_cell(&report.part_one, &measurements.part_one),
+                measurement_cell(&report.part_two, &measurements.part_two),
+            ]
+        })
+        .collect_vec();
+
+    // Numbers read better right-aligned, unlike the names of the examples next to the days
+    let examples = reports.iter().any(|report| report.example.is_some());
+    let mut lines = columns(&header, &rows, |col| col >= 3 || (col == 0 && !examples));
+
+    let total: Measurement = reports
+        .iter()
+        .map(|report| report.measurements.total())
+        .sum();
+    lines.push(format!("Total: {total}"));
+
+    let failures = reports
+        .iter()
+        .flat_map(|report| {
+            report
+                .outcomes()
+                .into_iter()
+                .filter_map(move |(part, outcome)| match outcome {
+                    Outcome::Failed(message) => Some(format!(
+                        "  day {} part {part}: {}",
+                        day_cell(report),
+                        /* Keeps multi-line diagnostics under their day */
+                        message.replace('\n', "\n    ")
+                    )),
+                    _ => None,
+                })
+        })
+        .collect_vec();
+
+    if !failures.is_empty() {
+        lines.push(String::new());
+        lines.push("Failures:".to_owned());
+        lines.extend(failures);
+    }
+
+    lines.join("\n")
+}
+
+/// The lines of a table with a header, its columns padded to the same width and left-aligned unless
+/// `right_aligned` says otherwise
+pub fn columns<const N: usize>(
+    header: &[String; N],
+    rows: &[[String; N]],
+    right_aligned: impl Fn(usize) -> bool,
+) -> Vec<String> {
+    let widths = (0..N)
+        .map(|col| {
+            std::iter::once(header)
+                .chain(rows)
+                .map(|row| row[col].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect_vec();
+
+    let format_row = |row: &[String; N]| {
+        row.iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(col, (text, &width))| {
+                if right_aligned(col) {
+                    format!("{text:>width$}")
+                } else {
+                    format!("{text:<width$}")
+                }
+            })
+            .join(" | ")
+    };
+
+    let mut lines = vec![
+        format_row(header),
+        widths.iter().map(|&width| "-".repeat(width)).join("-+-"),
+    ];
+    lines.extend(rows.iter().map(format_row));
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::Measurements;
+    use indoc::indoc;
+    use std::time::Duration;
+
+    fn measurements(parse: u64, part_one: u64, part_two: u64) -> Measurements {
+        let measurement = |millis| Measurement {
+            elapsed: Duration::from_millis(millis),
+            allocations: None,
+        };
+        Measurements {
+            parse: measurement(parse),
+            part_one: measurement(part_one),
+            part_two: measurement(part_two),
+        }
+    }
+
+    #[test]
+    fn test_table() {
+        let reports = [
+            Report {
+                day: 1,
+                example: None,
+                part_one: Outcome::Solved("142".to_owned()),
+                part_two: Outcome::Solved("281".to_owned()),
+                measurements: measurements(1, 2, 3),
+            },
+            Report {
+                day: 5,
+                example: None,
+                part_one: Outcome::Solved("35".to_owned()),
+                part_two: Outcome::Failed("panicked: not yet implemented".to_owned()),
+                measurements: measurements(1, 1, 0),
+            },
+            Report {
+                day: 6,
+                example: None,
+                part_one: Outcome::Solved("288".to_owned()),
+                part_two: Outcome::Skipped,
+                measurements: measurements(2, 1, 0),
+            },
+        ];
+
+        let expected = indoc! {"
+            Day | Part 1 | Part 2 |  Parse | Solve 1 | Solve 2
+            ----+--------+--------+--------+---------+--------
+             01 | 142    | 281    | 1.00ms |  2.00ms |  3.00ms
+             05 | 35     | FAILED | 1.00ms |  1.00ms |  0.00ns
+             06 | 288    | -      | 2.00ms |  1.00ms |       -
+            Total: 11.00ms
+
+            Failures:
+              day 05 part 2: panicked: not yet implemented"};
+
+        assert_eq!(table(&reports), expected);
+    }
+
+    #[test]
+    fn test_table_of_examples() {
+        let report = |example: &str, part_one: Outcome| Report {
+            day: 20,
+            example: Some(example.to_owned()),
+            part_one,
+            part_two: Outcome::Skipped,
+            measurements: measurements(1, 1, 0),
+        };
+        let reports = [
+            report("example", Outcome::Solved("32000000".to_owned())),
+            report(
+                "example-2",
+                Outcome::Failed("expected `11687500`".to_owned()),
+            ),
+        ];
+
+        let expected = indoc! {"
+            Day          | Part 1   | Part 2 |  Parse | Solve 1 | Solve 2
+            -------------+----------+--------+--------+---------+--------
+            20 example   | 32000000 | -      | 1.00ms |  1.00ms |       -
+            20 example-2 | FAILED   | -      | 1.00ms |  1.00ms |       -
+            Total: 4.00ms
+
+            Failures:
+              day 20 example-2 part 1: expected `11687500`"};
+
+        assert_eq!(table(&reports), expected);
+    }
+}
@@ -0,0 +1,241 @@
+use crate::output::{Record, Status};
+use anyhow::{bail, Context, Result};
+use common::YEAR;
+use notify::{EventKind, RecursiveMode, Watcher};
+use std::{
+    env,
+    ffi::OsString,
+    path::{Path, PathBuf},
+    process::Command,
+    sync::mpsc,
+    time::Duration,
+};
+
+/// How long to wait for an editor to be done saving before running, since saving a file often
+/// comes with several events
+const SETTLE: Duration = Duration::from_millis(200);
+
+/// Rebuilds and reruns a day every time its crate (sources, fixtures, manifest) or its input
+/// changes, printing how its answers changed since the previous run
+pub struct Watch {
+    workspace: PathBuf,
+    day: u8,
+    input: PathBuf,
+    previous: Option<Vec<Record>>,
+}
+
+impl Watch {
+    pub fn new(workspace: &Path, day: u8, input: &Path) -> Result<Self> {
+        /* Events come with absolute paths, which the input's has to be compared with */
+        let (Some(dir), Some(name)) = (input.parent(), input.file_name()) else {
+            bail!("Invalid input path {}", input.display());
+        };
+        let dir = if dir.as_os_str().is_empty() {
+            Path::new(".")
+        } else {
+            dir
+        };
+        let dir = dir
+            .canonicalize()
+            .with_context(|| format!("Could not find {}", dir.display()))?;
+
+        Ok(Self {
+            workspace: workspace.to_owned(),
+            day,
+            input: dir.join(name),
+            previous: None,
+        })
+    }
+
+    fn day_dir(&self) -> PathBuf {
+        self.workspace.join(format!("day-{:02}", self.day))
+    }
+
+    /// Runs the day once, then again after every change, until interrupted
+    pub fn run(mut self) -> Result<()> {
+        let (sender, events) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(sender)?;
+
+        let day_dir = self.day_dir();
+        watcher
+            .watch(&day_dir, RecursiveMode::Recursive)
+            .with_context(|| format!("Could not watch {}", day_dir.display()))?;
+        /* Editors often save by replacing the file, which a watch on the file itself wouldn't
+         * survive, so its directory is watched instead. */
+        let input_dir = self.input.parent().expect("The input path is absolute");
+        watcher
+            .watch(input_dir, RecursiveMode::NonRecursive)
+            .with_context(|| format!("Could not watch {}", input_dir.display()))?;
+
+        println!(
+            "Watching {} and {}",
+            day_dir.display(),
+            self.input.display()
+        );
+        self.cycle()?;
+
+        loop {
+            let event = events.recv()??;
+            let mut changed = self.relevant(&event);
+            while let Ok(event) = events.recv_timeout(SETTLE) {
+                changed.extend(self.relevant(&event?));
+            }
+            if changed.is_empty() {
+                continue;
+            }
+
+            changed.sort();
+            changed.dedup();
+            for path in &changed {
+                let path = path.strip_prefix(&self.workspace).unwrap_or(path);
+                println!("\n{} changed", path.display());
+            }
+            self.cycle()?;
+
+            /* Building and running the day touches files of its own, like proptest regressions,
+             * which shouldn't start another run. */
+            while events.try_recv().is_ok() {}
+        }
+    }
+
+    /// The paths of the event that concern the day
+    fn relevant(&self, event: &notify::Event) -> Vec<PathBuf> {
+        if !matches!(
+            event.kind,
+            EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+        ) {
+            return Vec::new();
+        }
+
+        let day_dir = self.day_dir();
+        event
+            .paths
+            .iter()
+            .filter(|path| path.starts_with(&day_dir) || **path == self.input)
+            .cloned()
+            .collect()
+    }
+
+    /// Rebuilds the runner, solves the input and checks the examples
+    fn cycle(&mut self) -> Result<()> {
+        println!("Building...");
+        let build = self
+            .cargo()
+            .args(["build", "--release", "--quiet", "-p", "aoc"])
+            .status()?;
+        if !build.success() {
+            println!("Build failed, waiting for the next change");
+            return Ok(());
+        }
+
+        let output = self
+            .cargo()
+            .args(["run", "--release", "--quiet", "-p", "aoc", "--", "run"])
+            .arg(YEAR.to_string())
+            .arg(self.day.to_string())
+            .arg("--input")
+            .arg(&self.input)
+            .args(["--format", "json"])
+            .output()?;
+        let records = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(|line| serde_json::from_str(line).context("Unexpected output of `aoc run`"))
+            .collect::<Result<Vec<Record>>>()?;
+        if records.is_empty() {
+            print!("{}", String::from_utf8_lossy(&output.stderr));
+        }
+        for line in changes(self.previous.as_deref(), &records) {
+            println!("{line}");
+        }
+        self.previous = Some(records);
+
+        let examples = self
+            .cargo()
+            .args(["test", "--quiet", "-p"])
+            .arg(format!("day-{:02}", self.day))
+            .args(["--lib", "test_examples"])
+            .output()?;
+        if examples.status.success() {
+            println!("Examples: ok");
+        } else {
+            println!("Examples: failed");
+            print!("{}", String::from_utf8_lossy(&examples.stdout));
+            print!("{}", String::from_utf8_lossy(&examples.stderr));
+        }
+
+        Ok(())
+    }
+
+    fn cargo(&self) -> Command {
+        let mut command = Command::new(env::var_os("CARGO").unwrap_or(OsString::from("cargo")));
+        command.current_dir(&self.workspace);
+        command
+    }
+}
+
+/// One line per part of `current`, noting the answers that differ from `previous`
+pub fn changes(previous: Option<&[Record]>, current: &[Record]) -> Vec<String> {
+    let result = |record: &Record| match record.status {
+        Status::Solved => record.answer.clone().unwrap_or_default(),
+        Status::Failed => format!("failed: {}", record.error.as_deref().unwrap_or_default()),
+    };
+
+    current
+        .iter()
+        .map(|record| {
+            let now = result(record);
+            let before = previous
+                .and_then(|previous| previous.iter().find(|r| r.part == record.part))
+                .map(result);
+            let note = match before {
+                Some(before) if before != now => format!("  (was {before})"),
+                Some(_) => "  (unchanged)".to_owned(),
+                None => String::new(),
+            };
+            format!(
+                "Part {}: {now}  [{:.3?}]{note}",
+                record.part,
+                Duration::from_secs_f64(record.duration)
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(part: u8, answer: Result<&str, &str>) -> Record {
+        Record {
+            year: YEAR,
+            day: 14,
+            part,
+            example: None,
+            answer: answer.ok().map(str::to_owned),
+            duration: 0.0015,
+            status: match answer {
+                Ok(_) => Status::Solved,
+                Err(_) => Status::Failed,
+            },
+            error: answer.err().map(str::to_owned),
+        }
+    }
+
+    #[test]
+    fn test_changes() {
+        let first = [record(1, Ok("136")), record(2, Ok("64"))];
+        assert_eq!(
+            changes(None, &first),
+            ["Part 1: 136  [1.500ms]", "Part 2: 64  [1.500ms]"]
+        );
+
+        let second = [record(1, Ok("136")), record(2, Err("no cycle"))];
+        assert_eq!(
+            changes(Some(&first), &second),
+            [
+                "Part 1: 136  [1.500ms]  (unchanged)",
+                "Part 2: failed: no cycle  [1.500ms]  (was 64)"
+            ]
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use crate::measure::Allocations;
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering::Relaxed},
+};
+
+/* Wraps the system allocator, counting every allocation and keeping track of how much memory is
+ * in use. Being a library, this crate can install it for every binary that enables the feature. */
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+static COUNT: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+struct CountingAllocator;
+
+impl CountingAllocator {
+    fn grow(size: usize) {
+        let current = CURRENT.fetch_add(size, Relaxed) + size;
+        PEAK.fetch_max(current, Relaxed);
+    }
+
+    fn shrink(size: usize) {
+        CURRENT.fetch_sub(size, Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            COUNT.fetch_add(1, Relaxed);
+            BYTES.fetch_add(layout.size(), Relaxed);
+            Self::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        Self::shrink(layout.size());
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            COUNT.fetch_add(1, Relaxed);
+            BYTES.fetch_add(layout.size(), Relaxed);
+            Self::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            COUNT.fetch_add(1, Relaxed);
+            BYTES.fetch_add(new_size, Relaxed);
+            Self::shrink(layout.size());
+            Self::grow(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// The counters when a measurement starts
+pub(crate) struct Start {
+    count: usize,
+    bytes: usize,
+    current: usize,
+}
+
+/// Resets the peak so that it only covers what happens from now on
+pub(crate) fn start() -> Start {
+    let current = CURRENT.load(Relaxed);
+    PEAK.store(current, Relaxed);
+    Start {
+        count: COUNT.load(Relaxed),
+        bytes: BYTES.load(Relaxed),
+        current,
+    }
+}
+
+pub(crate) fn since(start: Start) -> Allocations {
+    Allocations {
+        count: COUNT.load(Relaxed) - start.count,
+        bytes: BYTES.load(Relaxed) - start.bytes,
+        peak: PEAK.load(Relaxed).saturating_sub(start.current),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::measure;
+
+    #[test]
+    fn test_counts_allocations() {
+        let (_, measurement) = measure(|| vec![0u8; 1 << 20]);
+
+        let allocations = measurement.allocations.unwrap();
+        assert!(allocations.count >= 1);
+        assert!(allocations.peak >= 1 << 20);
+    }
+}
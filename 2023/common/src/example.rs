@@ -0,0 +1,167 @@
+use crate::{parse_input, Param, Params, Solution};
+use anyhow::{anyhow, bail, Context, Result};
+use std::{
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// A puzzle example kept on disk as `NAME.in`, next to a `NAME.answers` file such as:
+///
+/// ```text
+/// # Only part one is given for this example
+/// part 1 = 16
+/// param steps = 6
+/// ```
+///
+/// Parts without an expected answer aren't checked, so an example without any, like the one of a
+/// new day, is only parsed. Parameters override the day's defaults.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub params: Vec<(String, String)>,
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl Example {
+    /// Reads the example at `path` (the `.in` file) and its answers
+    pub fn load(path: &Path) -> Result<Self> {
+        let name = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .ok_or(anyhow!("Invalid example path {}", path.display()))?
+            .to_owned();
+        let input = fs::read_to_string(path)
+            .with_context(|| format!("Could not read example {}", path.display()))?;
+
+        let answers_path = path.with_extension("answers");
+        let answers = fs::read_to_string(&answers_path)
+            .with_context(|| format!("Could not read answers {}", answers_path.display()))?;
+
+        Self::new(name, input, &answers)
+            .with_context(|| format!("Invalid answers {}", answers_path.display()))
+    }
+
+    /// Every example of a `fixtures` directory, by name
+    pub fn all_in(dir: &Path) -> Result<Vec<Self>> {
+        let mut paths = fs::read_dir(dir)
+            .with_context(|| format!("Could not list examples in {}", dir.display()))?
+            .map(|entry| Ok(entry?.path()))
+            .collect::<Result<Vec<PathBuf>>>()?;
+        paths.retain(|path| path.extension().is_some_and(|ext| ext == "in"));
+        /* By name rather than by path, where `example-2.in` would come before `example.in` */
+        paths.sort_by(|a, b| a.file_stem().cmp(&b.file_stem()));
+
+        paths.iter().map(|path| Self::load(path)).collect()
+    }
+
+    fn new(name: String, input: String, answers: &str) -> Result<Self> {
+        let mut example = Self {
+            name,
+            input,
+            params: Vec::new(),
+            part_one: None,
+            part_two: None,
+        };
+
+        for line in answers.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or(anyhow!("Expected `KEY = VALUE`, got `{line}`"))?;
+            let value = value.trim().to_owned();
+            match key.split_whitespace().collect::<Vec<_>>()[..] {
+                ["part", "1"] => example.part_one = Some(value),
+                ["part", "2"] => example.part_two = Some(value),
+                ["param", name] => example.params.push((name.to_owned(), value)),
+                _ => bail!(
+                    "Expected `part 1`, `part 2` or `param NAME`, got `{}`",
+                    key.trim()
+                ),
+            }
+        }
+
+        Ok(example)
+    }
+
+    pub fn expected(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The day's default parameters, with this example's overrides
+    pub fn params(&self, declared: &'static [Param]) -> Result<Params> {
+        let mut params = Params::defaults(declared);
+        for (name, value) in &self.params {
+            params.set(name, value)?;
+        }
+        Ok(params)
+    }
+
+    /// Fails if `answer` isn't the expected answer to `part`, when there is one
+    pub fn compare(&self, part: u8, answer: &str) -> Result<()> {
+        match self.expected(part) {
+            Some(expected) if expected != answer => bail!(
+                "Example `{}` part {part}: expected `{expected}`, got `{answer}`",
+                self.name
+            ),
+            _ => Ok(()),
+        }
+    }
+
+    /// Solves the example and checks every part that has an expected answer
+    pub fn check<S: Solution>(&self) -> Result<()> {
+        let params = self.params(S::PARAMS)?;
+        let input = parse_input::<S>(&self.input)?;
+
+        if self.part_one.is_some() {
+            self.compare_answer(1, S::part_one(&input, &params)?)?;
+        }
+        if self.part_two.is_some() {
+            self.compare_answer(2, S::part_two(&input, &params)?)?;
+        }
+
+        Ok(())
+    }
+
+    fn compare_answer(&self, part: u8, answer: impl Display) -> Result<()> {
+        self.compare(part, &answer.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_answers() -> Result<()> {
+        let answers = indoc! {"
+            # The map only fits 6 steps
+            part 1 = 16
+            param steps = 6
+        "};
+        let example = Example::new("example".to_owned(), String::new(), answers)?;
+
+        assert_eq!(example.expected(1), Some("16"));
+        assert_eq!(example.expected(2), None);
+        assert_eq!(example.params, vec![("steps".to_owned(), "6".to_owned())]);
+
+        assert!(example.compare(1, "16").is_ok());
+        assert!(example.compare(1, "15").is_err());
+        assert!(example.compare(2, "anything").is_ok());
+
+        let empty = Example::new("empty".to_owned(), String::new(), "# nothing")?;
+        assert_eq!((empty.expected(1), empty.expected(2)), (None, None));
+        assert!(Example::new("typo".to_owned(), String::new(), "prat 1 = 3").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,146 @@
+use anyhow::{anyhow, Context, Result};
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Path to a single input file (or `-` for stdin), taking precedence over the inputs directory
+pub const INPUT_VAR: &str = "AOC_INPUT";
+/// Directory laid out as `<dir>/<year>/day-NN.in`
+pub const INPUTS_DIR_VAR: &str = "AOC_INPUTS_DIR";
+
+const DEFAULT_INPUTS_DIR: &str = "inputs";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Input {
+    /// `-` stands for stdin, anything else is a path
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Input::Stdin,
+            path => Input::File(PathBuf::from(path)),
+        }
+    }
+
+    pub fn read(&self) -> Result<String> {
+        match self {
+            Input::Stdin => io::read_to_string(io::stdin()).context("Could not read stdin"),
+            Input::File(path) => fs::read_to_string(path)
+                .with_context(|| format!("Could not read {}", path.display())),
+        }
+    }
+}
+
+/// Finds the input of a day: an explicit input wins, otherwise it is looked up in the inputs
+/// directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputLocator {
+    explicit: Option<Input>,
+    inputs_dir: PathBuf,
+}
+
+impl InputLocator {
+    pub fn new(inputs_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            explicit: None,
+            inputs_dir: inputs_dir.into(),
+        }
+    }
+
+    /// Uses `AOC_INPUT` and `AOC_INPUTS_DIR` when set. Without the latter, the closest `inputs`
+    /// directory in the current directory or one of its parents is used.
+    pub fn from_env() -> Self {
+        let inputs_dir = env::var_os(INPUTS_DIR_VAR)
+            .map(PathBuf::from)
+            .or_else(find_inputs_dir)
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUTS_DIR));
+
+        Self {
+            explicit: env::var(INPUT_VAR).ok().map(|arg| Input::from_arg(&arg)),
+            inputs_dir,
+        }
+    }
+
+    pub fn with_explicit(mut self, input: Input) -> Self {
+        self.explicit = Some(input);
+        self
+    }
+
+    pub fn with_inputs_dir(mut self, inputs_dir: impl Into<PathBuf>) -> Self {
+        self.inputs_dir = inputs_dir.into();
+        self
+    }
+
+    pub fn explicit(&self) -> Option<&Input> {
+        self.explicit.as_ref()
+    }
+
+    pub fn inputs_dir(&self) -> &Path {
+        &self.inputs_dir
+    }
+
+    /// Where the input of `day` is stored in the inputs directory, whether it exists or not
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.inputs_dir
+            .join(year.to_string())
+            .join(format!("day-{day:02}.in"))
+    }
+
+    pub fn locate(&self, year: u16, day: u8) -> Result<Input> {
+        if let Some(input) = &self.explicit {
+            return Ok(input.clone());
+        }
+
+        let path = self.path(year, day);
+        if path.is_file() {
+            Ok(Input::File(path))
+        } else {
+            Err(anyhow!(
+                "No input for {year} day {day:02}: {} does not exist (pass a path, `-` for stdin, \
+                 or set {INPUT_VAR} or {INPUTS_DIR_VAR})",
+                path.display()
+            ))
+        }
+    }
+
+    pub fn read(&self, year: u16, day: u8) -> Result<String> {
+        self.locate(year, day)?.read()
+    }
+}
+
+fn find_inputs_dir() -> Option<PathBuf> {
+    env::current_dir()
+        .ok()?
+        .ancestors()
+        .map(|dir| dir.join(DEFAULT_INPUTS_DIR))
+        .find(|dir| dir.is_dir())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locate() -> Result<()> {
+        let inputs_dir = env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        fs::create_dir_all(inputs_dir.join("2023"))?;
+        fs::write(inputs_dir.join("2023").join("day-07.in"), "32T3K 765")?;
+
+        let locator = InputLocator::new(&inputs_dir);
+        assert_eq!(locator.read(2023, 7)?, "32T3K 765");
+
+        let missing = locator.read(2023, 8).unwrap_err().to_string();
+        assert!(missing.starts_with("No input for 2023 day 08"));
+        assert!(missing.contains(&locator.path(2023, 8).display().to_string()));
+
+        let locator = locator.with_explicit(Input::from_arg("-"));
+        assert_eq!(locator.locate(2023, 8)?, Input::Stdin);
+
+        fs::remove_dir_all(inputs_dir)?;
+        Ok(())
+    }
+}
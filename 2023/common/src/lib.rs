@@ -0,0 +1,45 @@
+use anyhow::Result;
+use std::env;
+
+#[cfg(feature = "alloc-stats")]
+mod alloc;
+mod example;
+mod input;
+mod measure;
+pub mod parse;
+mod random;
+mod solution;
+
+pub use example::Example;
+pub use input::{Input, InputLocator, INPUTS_DIR_VAR, INPUT_VAR};
+pub use measure::{measure, Allocations, Measurement};
+pub use parse::{parse_input, ParseError, Span};
+pub use random::Rng;
+pub use solution::{Assumption, Param, Params, Solution};
+
+/// The year every solution in this workspace belongs to
+pub const YEAR: u16 = 2023;
+
+/// Entry point of the `day-NN` binaries: solves the input given as the first argument (a path, or
+/// `-` for stdin) or found by `InputLocator::from_env` with the default parameters, and reports how
+/// long parsing and each part took. Build with `--features common/alloc-stats` to also report
+/// heap usage.
+pub fn main<S: Solution>() -> Result<()> {
+    let mut locator = InputLocator::from_env();
+    if let Some(arg) = env::args().nth(1) {
+        locator = locator.with_explicit(Input::from_arg(&arg));
+    }
+    let input = locator.read(YEAR, S::DAY)?;
+
+    let (input, parse) = measure(|| parse_input::<S>(&input));
+    let input = input?;
+    println!("Parse: {parse}");
+
+    let params = Params::defaults(S::PARAMS);
+    let (answer, part_one) = measure(|| S::part_one(&input, &params));
+    println!("Part 1: {} ({part_one})", answer?);
+    let (answer, part_two) = measure(|| S::part_two(&input, &params));
+    println!("Part 2: {} ({part_two})", answer?);
+
+    Ok(())
+}
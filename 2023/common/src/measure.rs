@@ -0,0 +1,130 @@
+use std::{
+    fmt::Display,
+    iter::Sum,
+    ops::Add,
+    time::{Duration, Instant},
+};
+
+/// How long something took and, with the `alloc-stats` feature, how much it allocated
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Measurement {
+    pub elapsed: Duration,
+    pub allocations: Option<Allocations>,
+}
+
+/// Heap usage while something ran: `peak` is the most memory it held at once, on top of what was
+/// already allocated before it started.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Allocations {
+    pub count: usize,
+    pub bytes: usize,
+    pub peak: usize,
+}
+
+/// Runs `f`, measuring it. Allocations are counted process-wide, so other threads allocating at
+/// the same time end up in the counts too.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Measurement) {
+    #[cfg(feature = "alloc-stats")]
+    let before = crate::alloc::start();
+
+    let start = Instant::now();
+    let value = f();
+    let elapsed = start.elapsed();
+
+    #[cfg(feature = "alloc-stats")]
+    let allocations = Some(crate::alloc::since(before));
+    #[cfg(not(feature = "alloc-stats"))]
+    let allocations = None;
+
+    (
+        value,
+        Measurement {
+            elapsed,
+            allocations,
+        },
+    )
+}
+
+impl Add for Measurement {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let allocations = match (self.allocations, other.allocations) {
+            (Some(a), Some(b)) => Some(Allocations {
+                count: a.count + b.count,
+                bytes: a.bytes + b.bytes,
+                peak: a.peak.max(b.peak),
+            }),
+            (a, b) => a.or(b),
+        };
+
+        Self {
+            elapsed: self.elapsed + other.elapsed,
+            allocations,
+        }
+    }
+}
+
+impl Sum for Measurement {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
+impl Display for Measurement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.2?}", self.elapsed)?;
+        if let Some(allocations) = self.allocations {
+            write!(f, ", {allocations}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Allocations {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} allocs, {} peak", self.count, ByteSize(self.peak))
+    }
+}
+
+struct ByteSize(usize);
+
+impl Display for ByteSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+        let mut size = self.0 as f64;
+        let mut unit = 0;
+        while size >= 1024.0 && unit < UNITS.len() - 1 {
+            size /= 1024.0;
+            unit += 1;
+        }
+
+        match unit {
+            0 => write!(f, "{} B", self.0),
+            _ => write!(f, "{size:.1} {}", UNITS[unit]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let allocations = Allocations {
+            count: 12,
+            bytes: 4096,
+            peak: 3 * 1024 * 1024 / 2,
+        };
+        let measurement = Measurement {
+            elapsed: Duration::from_micros(1500),
+            allocations: Some(allocations),
+        };
+        assert_eq!(measurement.to_string(), "1.50ms, 12 allocs, 1.5 MiB peak");
+
+        let total: Measurement = [measurement, measurement].into_iter().sum();
+        assert_eq!(total.to_string(), "3.00ms, 24 allocs, 1.5 MiB peak");
+    }
+}
@@ -0,0 +1,278 @@
+use crate::Solution;
+use std::{error::Error, fmt::Display, str::FromStr};
+
+/// Where a piece of text is in the input, 1-based and counted in characters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub line: usize,
+    pub column: usize,
+    pub len: usize,
+}
+
+/// A malformed input: what was found where something else was expected.
+///
+/// Parsers create it from the offending slice of the input, wherever they are in it. Once it
+/// bubbles up to `parse_input`, the slice is located in the whole input so that the error can be
+/// shown like a compiler diagnostic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    found: String,
+    expected: String,
+    /* The address range of `found` in the parser's input, only compared against the address of the
+     * whole input in `locate`, never dereferenced. */
+    address: (usize, usize),
+    location: Option<(Span, String)>,
+}
+
+impl ParseError {
+    /// `found` should be a slice of the input (possibly empty, for missing text) so that it can be
+    /// located later on.
+    pub fn new(found: &str, expected: impl Into<String>) -> Self {
+        let start = found.as_ptr() as usize;
+        Self {
+            found: found.to_owned(),
+            expected: expected.into(),
+            address: (start, start + found.len()),
+            location: None,
+        }
+    }
+
+    pub fn found(&self) -> &str {
+        &self.found
+    }
+
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    pub fn span(&self) -> Option<Span> {
+        self.location.as_ref().map(|(span, _)| *span)
+    }
+
+    /// Finds the offending text in `input`, unless it is not a slice of it
+    pub fn locate(&mut self, input: &str) {
+        let base = input.as_ptr() as usize;
+        let (start, end) = self.address;
+        if start < base || end > base + input.len() {
+            return;
+        }
+
+        let offset = start - base;
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+
+        let span = Span {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            len: self.found.chars().count(),
+        };
+        let line = input[line_start..line_end]
+            .trim_end_matches('\r')
+            .to_owned();
+        self.location = Some((span, line));
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.found.as_str() {
+            "" => write!(f, "expected {}, found nothing", self.expected)?,
+            found => write!(f, "expected {}, found `{found}`", self.expected)?,
+        }
+
+        if let Some((span, line)) = &self.location {
+            let number = span.line.to_string();
+            let gutter = " ".repeat(number.len());
+            write!(
+                f,
+                "\n{gutter}--> line {}, column {}\n{gutter} |\n{number} | {line}\n{gutter} | {}{}",
+                span.line,
+                span.column,
+                " ".repeat(span.column - 1),
+                "^".repeat(span.len.max(1)),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `input` with `S::parse`, pointing any `ParseError` at its place in `input`
+pub fn parse_input<S: Solution + ?Sized>(input: &str) -> anyhow::Result<S::Input> {
+    S::parse(input).map_err(|mut err| {
+        if let Some(parse_error) = err.downcast_mut::<ParseError>() {
+            parse_error.locate(input);
+        }
+        err
+    })
+}
+
+/// Parses a number (or anything else from its text), trimming surrounding whitespace
+pub fn number<T: FromStr>(text: &str) -> Result<T, ParseError> {
+    let text = text.trim();
+    text.parse().map_err(|_| ParseError::new(text, "a number"))
+}
+
+/// `str::split_once`, failing with the whole text when `delimiter` is missing from it
+pub fn split_once<'a>(text: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(text, format!("`{delimiter}`")))
+}
+
+/// Whitespace separated numbers, like `79 14 55 13`
+pub fn numbers<T: FromStr>(text: &str) -> Result<Vec<T>, ParseError> {
+    text.split_whitespace().map(number).collect()
+}
+
+/// The label and value of a `LABEL: VALUE` line (or section), both trimmed
+pub fn header(text: &str) -> Result<(&str, &str), ParseError> {
+    let (label, value) = text
+        .split_once(':')
+        .ok_or_else(|| ParseError::new(text, "`LABEL: VALUE`"))?;
+    Ok((label.trim(), value.trim()))
+}
+
+/// `str::strip_prefix`, failing with the start of the text when it doesn't match
+pub fn prefixed<'a>(text: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    text.strip_prefix(prefix).ok_or_else(|| {
+        let end = text
+            .char_indices()
+            .map(|(i, _)| i)
+            .nth(prefix.chars().count())
+            .unwrap_or(text.len());
+        ParseError::new(&text[..end], format!("`{prefix}`"))
+    })
+}
+
+/// The text between `open` and `close`, like the inside of `(BBB, CCC)`
+pub fn delimited(text: &str, open: char, close: char) -> Result<&str, ParseError> {
+    let inner = text.strip_prefix(open).ok_or_else(|| {
+        let first = text.chars().next().map_or(0, char::len_utf8);
+        ParseError::new(&text[..first], format!("`{open}`"))
+    })?;
+    inner
+        .strip_suffix(close)
+        .ok_or_else(|| ParseError::new(&inner[inner.len()..], format!("`{close}`")))
+}
+
+/// The items of a `separator` separated list, trimmed. Empty items are an error.
+pub fn list<'a>(text: &'a str, separator: &str) -> Result<Vec<&'a str>, ParseError> {
+    text.split(separator)
+        .map(|item| match item.trim() {
+            "" => Err(ParseError::new(item, "an item")),
+            item => Ok(item),
+        })
+        .collect()
+}
+
+/// Exactly `N` fields separated by `separator`, like the `[LEFT, RIGHT]` of `LEFT, RIGHT`
+pub fn fields<'a, const N: usize>(
+    text: &'a str,
+    separator: &str,
+) -> Result<[&'a str; N], ParseError> {
+    let fields = text.split(separator).collect::<Vec<_>>();
+    fields
+        .try_into()
+        .map_err(|_| ParseError::new(text, format!("{N} fields separated by `{separator}`")))
+}
+
+/// The blocks of `input` separated by blank lines, which don't have to be completely empty
+pub fn sections(input: &str) -> Vec<&str> {
+    let mut sections = Vec::new();
+    let mut start = None;
+    let mut offset = 0;
+
+    for line in input.split_inclusive('\n') {
+        match (line.trim().is_empty(), start) {
+            (true, Some(section_start)) => {
+                sections.push(input[section_start..offset].trim_end());
+                start = None;
+            }
+            (false, None) => start = Some(offset),
+            _ => (),
+        }
+        offset += line.len();
+    }
+    if let Some(section_start) = start {
+        sections.push(input[section_start..].trim_end());
+    }
+
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_diagnostic() {
+        let input = "Game 1: 3 blue\nGame 2: x red, 1 green\n";
+        let line = input.lines().nth(1).unwrap();
+
+        let mut error = number::<u32>(&line[8..10]).unwrap_err();
+        assert_eq!(error.to_string(), "expected a number, found `x`");
+
+        error.locate(input);
+        assert_eq!(
+            error.span(),
+            Some(Span {
+                line: 2,
+                column: 9,
+                len: 1
+            })
+        );
+
+        let expected = indoc! {"
+            expected a number, found `x`
+             --> line 2, column 9
+              |
+            2 | Game 2: x red, 1 green
+              |         ^"};
+        assert_eq!(error.to_string(), expected);
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = "\n  a\n  b\n  \n\nc\n";
+        assert_eq!(sections(input), ["  a\n  b", "c"]);
+    }
+
+    #[test]
+    fn test_line_formats() -> Result<(), ParseError> {
+        assert_eq!(numbers::<u32>(" 79 14  55 ")?, [79, 14, 55]);
+        assert_eq!(header("Card  1: 41 48 | 83")?, ("Card  1", "41 48 | 83"));
+        assert_eq!(prefixed("seeds: 79", "seeds:")?, " 79");
+        assert_eq!(delimited("(BBB, CCC)", '(', ')')?, "BBB, CCC");
+        assert_eq!(list("a, b,c", ",")?, ["a", "b", "c"]);
+        assert_eq!(fields::<2>("BBB, CCC", ", ")?, ["BBB", "CCC"]);
+
+        assert_eq!(numbers::<u32>("1 x").unwrap_err().found(), "x");
+        assert_eq!(header("Card 1").unwrap_err().expected(), "`LABEL: VALUE`");
+        assert_eq!(
+            prefixed("seed: 79", "seeds:").unwrap_err().found(),
+            "seed: "
+        );
+        assert_eq!(delimited("BBB, CCC)", '(', ')').unwrap_err().found(), "B");
+        assert_eq!(delimited("(BBB, CCC", '(', ')').unwrap_err().found(), "");
+        assert_eq!(list("a, , c", ",").unwrap_err().found(), " ");
+        assert_eq!(
+            fields::<2>("BBB, CCC, DDD", ", ").unwrap_err().expected(),
+            "2 fields separated by `, `"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_not_in_input() {
+        let mut error = split_once("no colon", ":").unwrap_err();
+        error.locate("some other input");
+
+        assert_eq!(error.span(), None);
+        assert_eq!(error.to_string(), "expected `:`, found `no colon`");
+    }
+}
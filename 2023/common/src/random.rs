@@ -0,0 +1,96 @@
+use std::ops::Range;
+
+/// A small splitmix64 generator for making up puzzle inputs. It isn't meant to be unpredictable,
+/// only reproducible: the same seed gives the same numbers on every platform and every run.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`, which must not be empty
+    pub fn range(&mut self, range: Range<usize>) -> usize {
+        assert!(!range.is_empty(), "Can't pick from an empty range");
+        /* The modulo bias is negligible for the small ranges inputs are made of */
+        range.start + (self.next_u64() % (range.end - range.start) as u64) as usize
+    }
+
+    /// True once every `n` times on average
+    pub fn one_in(&mut self, n: usize) -> bool {
+        self.range(0..n) == 0
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0..i + 1));
+        }
+    }
+
+    /// `len` lowercase letters
+    pub fn letters(&mut self, len: usize) -> String {
+        (0..len)
+            .map(|_| char::from(b'a' + self.range(0..26) as u8))
+            .collect()
+    }
+
+    /// `count` lines made by `line`, which is given the index of each one
+    pub fn lines(
+        &mut self,
+        count: usize,
+        mut line: impl FnMut(&mut Self, usize) -> String,
+    ) -> String {
+        (0..count).map(|i| line(self, i) + "\n").collect()
+    }
+
+    /// A `width` by `height` grid of the tiles made by `tile`
+    pub fn grid(
+        &mut self,
+        width: usize,
+        height: usize,
+        mut tile: impl FnMut(&mut Self) -> char,
+    ) -> String {
+        self.lines(height, |rng, _| (0..width).map(|_| tile(rng)).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng() {
+        /* The first outputs of the reference implementation seeded with 1234567 */
+        let mut rng = Rng::new(1234567);
+        assert_eq!(rng.next_u64(), 6457827717110365317);
+        assert_eq!(rng.next_u64(), 3203168211198807973);
+
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..100).map(|_| rng.range(3..10)).collect::<Vec<_>>()
+        };
+        assert_eq!(numbers(7), numbers(7));
+        assert_ne!(numbers(7), numbers(8));
+        assert!(numbers(7).iter().all(|n| (3..10).contains(n)));
+
+        let mut items = (0..10).collect::<Vec<_>>();
+        rng.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, (0..10).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{parse_input, Rng};
+use anyhow::{anyhow, Result};
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+/// A value that tweaks how a day is solved without being part of the puzzle input, like the number
+/// of steps in day 21 or the expansion factor in day 11.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: &'static str,
+    pub description: &'static str,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+    values: HashMap<&'static str, String>,
+}
+
+impl Params {
+    pub fn defaults(params: &'static [Param]) -> Self {
+        Self {
+            values: params
+                .iter()
+                .map(|param| (param.name, param.default.to_owned()))
+                .collect(),
+        }
+    }
+
+    /// Overrides a declared parameter. Unknown names are an error so that typos don't go unnoticed.
+    pub fn set(&mut self, name: &str, value: impl ToString) -> Result<()> {
+        let value_slot = self
+            .values
+            .get_mut(name)
+            .ok_or(anyhow!("Unknown parameter `{name}`"))?;
+        *value_slot = value.to_string();
+        Ok(())
+    }
+
+    pub fn with(mut self, name: &str, value: impl ToString) -> Result<Self> {
+        self.set(name, value)?;
+        Ok(self)
+    }
+
+    pub fn get<T>(&self, name: &str) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let value = self
+            .values
+            .get(name)
+            .ok_or(anyhow!("Unknown parameter `{name}`"))?;
+
+        value
+            .parse()
+            .map_err(|e| anyhow!("Invalid value `{value}` for parameter `{name}`: {e}"))
+    }
+}
+
+/// Something a solution takes for granted about its input instead of handling every input the
+/// puzzle allows, and whether a given input lives up to it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assumption {
+    pub description: String,
+    /// Why the input breaks the assumption, if it does
+    pub violation: Option<String>,
+}
+
+impl Assumption {
+    pub fn new(description: impl Into<String>, outcome: Result<(), String>) -> Self {
+        Self {
+            description: description.into(),
+            violation: outcome.err(),
+        }
+    }
+
+    /// An assumption that holds whenever `holds` is true, with nothing more to say when it doesn't
+    pub fn check(description: impl Into<String>, holds: bool) -> Self {
+        Self::new(description, holds.then_some(()).ok_or_else(String::new))
+    }
+
+    pub fn holds(&self) -> bool {
+        self.violation.is_none()
+    }
+}
+
+/// The shape every day is solved in: the input is parsed once and then shared by both parts.
+pub trait Solution {
+    const DAY: u8;
+    const TITLE: &'static str;
+    const PARAMS: &'static [Param] = &[];
+
+    type Input;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part_one(input: &Self::Input, params: &Params) -> Result<Self::PartOne>;
+    fn part_two(input: &Self::Input, params: &Params) -> Result<Self::PartTwo>;
+
+    /// Makes up an input that `parse` accepts, about `size` lines or rows long, for the days that
+    /// know how to. The same `rng` state always gives the same input.
+    fn generate(_rng: &mut Rng, _size: usize) -> Option<String> {
+        None
+    }
+
+    /// What the solution relies on about `input` without checking it while solving, so that it can
+    /// be checked before trusting the answers to a new input
+    fn assumptions(_input: &Self::Input) -> Vec<Assumption> {
+        Vec::new()
+    }
+
+    /// Parses `input` and solves part one with the default parameters.
+    fn solve_one(input: &str) -> Result<Self::PartOne> {
+        Self::part_one(
+            &parse_input::<Self>(input)?,
+            &Params::defaults(Self::PARAMS),
+        )
+    }
+
+    /// Parses `input` and solves part two with the default parameters.
+    fn solve_two(input: &str) -> Result<Self::PartTwo> {
+        Self::part_two(
+            &parse_input::<Self>(input)?,
+            &Params::defaults(Self::PARAMS),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARAMS: &[Param] = &[Param {
+        name: "steps",
+        default: "64",
+        description: "Number of steps",
+    }];
+
+    #[test]
+    fn test_params() -> Result<()> {
+        let params = Params::defaults(PARAMS);
+        assert_eq!(params.get::<usize>("steps")?, 64);
+
+        let params = params.with("steps", 6)?;
+        assert_eq!(params.get::<usize>("steps")?, 6);
+
+        assert!(params.get::<usize>("stpes").is_err());
+        assert!(params.clone().with("stpes", 6).is_err());
+        assert!(params.with("steps", "many")?.get::<usize>("steps").is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,138 @@
+use anyhow::{anyhow, Result};
+use common::{Params, ParseError, Rng, Solution};
+
+const DIGITS: [(&str, char); 9] = [
+    ("one", '1'),
+    ("two", '2'),
+    ("three", '3'),
+    ("four", '4'),
+    ("five", '5'),
+    ("six", '6'),
+    ("seven", '7'),
+    ("eight", '8'),
+    ("nine", '9'),
+];
+
+/// The number made of the first and last digit of `line`, counting spelled out digits as well if
+/// `english_numerals` is set. `None` if there are no digits at all.
+pub fn parse_line(line: &str, english_numerals: bool) -> Option<u32> {
+    let mut index = 0;
+    let line_iter = std::iter::from_fn(move || {
+        let reduced_line = &line[index..];
+
+        if english_numerals {
+            for (s, d) in DIGITS {
+                if reduced_line.starts_with(s) {
+                    index += 1;
+                    return Some(d);
+                }
+            }
+        }
+
+        index += 1;
+        reduced_line.chars().next()
+    });
+
+    let mut it = line_iter.filter_map(|c| c.to_digit(10));
+    let first = it.next()?;
+    let last = match it.last() {
+        Some(d) => d,
+        None => first,
+    };
+
+    Some(10 * first + last)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Trebuchet?!";
+
+    type Input = Vec<String>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .map(|line| match parse_line(line, true) {
+                Some(_) => Ok(line.to_owned()),
+                None => Err(ParseError::new(line, "a digit").into()),
+            })
+            .collect()
+    }
+
+    fn part_one(lines: &Self::Input, _: &Params) -> Result<u32> {
+        calibration_sum(lines, false)
+    }
+
+    fn part_two(lines: &Self::Input, _: &Params) -> Result<u32> {
+        calibration_sum(lines, true)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(rng.lines(size, |rng, _| {
+            let pieces = rng.range(1..10);
+            /* Part one needs at least one actual digit on every line */
+            let digit = rng.range(0..pieces);
+            (0..pieces)
+                .map(|piece| match rng.range(0..4) {
+                    _ if piece == digit => rng.range(1..10).to_string(),
+                    0 => rng.pick(&DIGITS).0.to_owned(),
+                    _ => {
+                        let len = rng.range(1..4);
+                        rng.letters(len)
+                    }
+                })
+                .collect()
+        }))
+    }
+}
+
+/* Every line has a digit once spelled out ones count, that's checked while parsing. Only part one
+ * can still run into a line without any. */
+fn calibration_sum(lines: &[String], english_numerals: bool) -> Result<u32> {
+    lines
+        .iter()
+        .map(|line| {
+            parse_line(line, english_numerals).ok_or(anyhow!("No numeric digit in `{line}`"))
+        })
+        .sum()
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use common::Example;
+    use rstest::*;
+    use std::path::PathBuf;
+
+    #[rstest]
+    #[case("1abc2", 12)]
+    #[case("pqr3stu8vwx", 38)]
+    #[case("a1b2c3d4e5f", 15)]
+    #[case("treb7uchet", 77)]
+    fn test_parse_line_no_numerals(#[case] line: &str, #[case] expected: u32) {
+        assert_eq!(expected, parse_line(line, false).unwrap());
+    }
+
+    #[rstest]
+    #[case("two1nine", 29)]
+    #[case("eightwothree", 83)]
+    #[case("abcone2threexyz", 13)]
+    #[case("xtwone3four", 24)]
+    #[case("4nineeightseven2", 42)]
+    #[case("zoneight234", 14)]
+    #[case("7pqrstsixteen", 76)]
+    /* All overlapping English numerals should be included in the parsed output */
+    #[case("twothreesixthreecvsskxq3threefoureight", 28)]
+    fn test_parse_line(#[case] line: &str, #[case] expected: u32) {
+        assert_eq!(expected, parse_line(line, true).unwrap());
+    }
+
+    #[rstest]
+    fn test_examples(#[files("fixtures/*.in")] path: PathBuf) -> Result<()> {
+        Example::load(&path)?.check::<Day01>()
+    }
+}
@@ -0,0 +1,129 @@
+use anyhow::Result;
+use common::{parse, Param, Params, ParseError, Rng, Solution};
+
+/// The (red, green, blue) cubes shown in one bunch, e.g. `3 blue, 4 red`
+pub fn process_bunch(bunch: &str) -> Result<(u32, u32, u32), ParseError> {
+    let (mut r, mut g, mut b) = (0, 0, 0);
+    for item in parse::list(bunch, ",")? {
+        let [count, color] = parse::fields(item, " ")?;
+        let count = parse::number::<u32>(count)?;
+        match color {
+            "red" => r += count,
+            "green" => g += count,
+            "blue" => b += count,
+            _ => return Err(ParseError::new(color, "`red`, `green` or `blue`")),
+        }
+    }
+    Ok((r, g, b))
+}
+
+/// The fewest (red, green, blue) cubes the game on `line` could have been played with
+pub fn min_cube_set(line: &str) -> Result<(u32, u32, u32), ParseError> {
+    let (_, bunches) = parse::header(line)?;
+    parse::list(bunches, ";")?
+        .into_iter()
+        .map(process_bunch)
+        .try_fold((0, 0, 0), |(acc_r, acc_g, acc_b), bunch| {
+            let (r, g, b) = bunch?;
+            Ok((acc_r.max(r), acc_g.max(g), acc_b.max(b)))
+        })
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Cube Conundrum";
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "red",
+            default: "12",
+            description: "Red cubes in the bag",
+        },
+        Param {
+            name: "green",
+            default: "13",
+            description: "Green cubes in the bag",
+        },
+        Param {
+            name: "blue",
+            default: "14",
+            description: "Blue cubes in the bag",
+        },
+    ];
+
+    type Input = Vec<(u32, u32, u32)>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(min_cube_set).collect::<Result<_, _>>()?)
+    }
+
+    fn part_one(games: &Self::Input, params: &Params) -> Result<u32> {
+        let (red_cubes, green_cubes, blue_cubes) = (
+            params.get("red")?,
+            params.get("green")?,
+            params.get("blue")?,
+        );
+
+        Ok(games
+            .iter()
+            .enumerate()
+            .filter(|&(_, &(r, g, b))| r <= red_cubes && g <= green_cubes && b <= blue_cubes)
+            .fold(0, |acc, (i, _)| acc + (i + 1) as u32))
+    }
+
+    fn part_two(games: &Self::Input, _: &Params) -> Result<u32> {
+        Ok(games.iter().map(|(r, g, b)| r * g * b).sum())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(rng.lines(size, |rng, game| {
+            let bunches = (0..rng.range(1..7))
+                .map(|_| {
+                    let mut colors = ["red", "green", "blue"];
+                    rng.shuffle(&mut colors);
+                    let shown = rng.range(1..4);
+                    colors[..shown]
+                        .iter()
+                        .map(|color| format!("{} {color}", rng.range(1..21)))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                })
+                .collect::<Vec<_>>();
+            format!("Game {}: {}", game + 1, bunches.join("; "))
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Example;
+    use rstest::*;
+    use std::path::PathBuf;
+
+    #[rstest]
+    #[case("3 blue, 4 red, 4 red", (8, 0, 3))]
+    #[case("8 green, 6 blue, 20 red", (20, 8, 6))]
+    #[case("1 green, 3 red, 6 blue, 3 green, 6 red, 3 green", (9, 7, 6))]
+    fn test_process_bunch(#[case] bunch: &str, #[case] expected: (u32, u32, u32)) {
+        assert_eq!(process_bunch(bunch).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green", (4, 2, 6))]
+    #[case("Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue", (1, 3, 4))]
+    #[case("Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red", (20, 13, 6))]
+    #[case("Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red", (14, 3, 15))]
+    #[case("Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green", (6, 3, 2))]
+    fn test_min_cube_set(#[case] line: &str, #[case] expected: (u32, u32, u32)) {
+        assert_eq!(min_cube_set(line).unwrap(), expected);
+    }
+
+    #[rstest]
+    fn test_examples(#[files("fixtures/*.in")] path: PathBuf) -> Result<()> {
+        Example::load(&path)?.check::<Day02>()
+    }
+}
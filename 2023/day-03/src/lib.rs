@@ -0,0 +1,115 @@
+use anyhow::Result;
+use common::{Params, Rng, Solution};
+use grid::{Grid, Point};
+use std::collections::{HashMap, HashSet};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Gear Ratios";
+
+    type Input = Grid<char>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::from_rows(input.lines().map(|line| line.chars().collect()).collect())
+    }
+
+    fn part_one(schematic: &Self::Input, _: &Params) -> Result<u32> {
+        Ok(numbers(schematic)
+            .into_iter()
+            .filter(|(_, digits)| {
+                digits
+                    .iter()
+                    .flat_map(|&digit| schematic.neighbours8(digit))
+                    .any(|point| is_symbol(schematic[point]))
+            })
+            .map(|(number, _)| number)
+            .sum())
+    }
+
+    fn part_two(schematic: &Self::Input, _: &Params) -> Result<u32> {
+        let mut gears: HashMap<Point, Vec<u32>> = HashMap::new();
+        for (number, digits) in numbers(schematic) {
+            let adjacent_gears: HashSet<Point> = digits
+                .iter()
+                .flat_map(|&digit| schematic.neighbours8(digit))
+                .filter(|&point| schematic[point] == '*')
+                .collect();
+
+            for gear in adjacent_gears {
+                gears.entry(gear).or_default().push(number);
+            }
+        }
+
+        Ok(gears
+            .values()
+            .filter(|numbers| numbers.len() == 2)
+            .map(|numbers| numbers[0] * numbers[1])
+            .sum())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(rng.lines(size, |rng, _| {
+            let mut row = String::new();
+            while row.len() < size {
+                let number = rng.range(1..1000).to_string();
+                match rng.range(0..10) {
+                    /* Followed by a `.` so that the next number doesn't get glued to it */
+                    0 | 1 if row.len() + number.len() < size => row.push_str(&(number + ".")),
+                    2 => row.push(*rng.pick(&['*', '#', '+', '$', '/', '@', '=', '%', '&', '-'])),
+                    _ => row.push('.'),
+                }
+            }
+            row
+        }))
+    }
+}
+
+pub fn is_symbol(ch: char) -> bool {
+    !ch.is_ascii_digit() && ch != '.'
+}
+
+/// Every number in the schematic, along with the positions of its digits
+pub fn numbers(schematic: &Grid<char>) -> Vec<(u32, Vec<Point>)> {
+    let mut numbers = Vec::new();
+    for (row, tiles) in schematic.rows().enumerate() {
+        let mut col = 0;
+        while col < tiles.len() {
+            let length = tiles[col..]
+                .iter()
+                .take_while(|ch| ch.is_ascii_digit())
+                .count();
+
+            if length > 0 {
+                let number = tiles[col..col + length]
+                    .iter()
+                    .fold(0, |acc, ch| acc * 10 + ch.to_digit(10).unwrap());
+                let digits = (col..col + length)
+                    .map(|col| Point::new(row, col))
+                    .collect();
+
+                numbers.push((number, digits));
+            }
+
+            col += length + 1;
+        }
+    }
+
+    numbers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Example;
+    use rstest::*;
+    use std::path::PathBuf;
+
+    #[rstest]
+    fn test_examples(#[files("fixtures/*.in")] path: PathBuf) -> Result<()> {
+        Example::load(&path)?.check::<Day03>()
+    }
+}
@@ -0,0 +1,102 @@
+use anyhow::Result;
+use common::{parse, Params, ParseError, Rng, Solution};
+use std::collections::BTreeSet;
+
+/// How many of the card's numbers are winning numbers
+pub fn card_score(card: &str) -> Result<u32, ParseError> {
+    let (_, numbers) = parse::header(card)?;
+    let [winning_numbers, given_numbers] = parse::fields(numbers, "|")?;
+
+    let winning_numbers = BTreeSet::from_iter(parse::numbers::<u32>(winning_numbers)?);
+    let given_numbers = BTreeSet::from_iter(parse::numbers::<u32>(given_numbers)?);
+
+    Ok(winning_numbers.intersection(&given_numbers).count() as u32)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Scratchcards";
+
+    type Input = Vec<u32>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .map(card_score)
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part_one(scores: &Self::Input, _: &Params) -> Result<u32> {
+        Ok(scores
+            .iter()
+            .map(|&score| if score != 0 { 2u32.pow(score - 1) } else { 0 })
+            .sum())
+    }
+
+    fn part_two(scores: &Self::Input, _: &Params) -> Result<u32> {
+        let mut card_counts: Vec<u32> = vec![1; scores.len()];
+
+        scores.iter().enumerate().for_each(|(card, &score)| {
+            let card_count = card_counts[card];
+            card_counts[card + 1..=card + score as usize]
+                .iter_mut()
+                .for_each(|count| *count += card_count);
+        });
+
+        Ok(card_counts.iter().sum())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        /* Copies pile up exponentially along a chain of winning cards, so cards come in batches of
+         * ten that only win copies within their batch, which keeps the totals in a u32. */
+        Some(rng.lines(size, |rng, card| {
+            let batch_end = ((card / 10 + 1) * 10).min(size);
+            let matches = rng.range(0..(batch_end - card).min(11));
+
+            let mut numbers = (1..100).collect::<Vec<_>>();
+            rng.shuffle(&mut numbers);
+            let (winning, others) = numbers.split_at(10);
+            let mut given = [&winning[..matches], &others[..25 - matches]].concat();
+            rng.shuffle(&mut given);
+
+            let show = |numbers: &[usize]| {
+                numbers
+                    .iter()
+                    .map(|n| format!("{n:>2}"))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            };
+            format!("Card {:>3}: {} | {}", card + 1, show(winning), show(&given))
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Example;
+    use rstest::*;
+    use std::path::PathBuf;
+
+    #[rstest]
+    #[case("Card 2: 41 48 83 86 17  | 83 86  6 31 17  9 48 53", 4)]
+    #[case("Card 2: 13 32 20 16 61   |        61 30 68 82 17 32 24 19", 2)]
+    #[case("Card 3:  1 21 53 59 44 | 69      82 63 72 16 21 14  1", 2)]
+    #[case("Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83", 1)]
+    #[case("Card 5: 87 83 26 28 32 | 88 30 70 12 93 22       82 36", 0)]
+    #[case("Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11", 0)]
+    fn test_card_score(#[case] card: &str, #[case] expected: u32) {
+        assert_eq!(expected, card_score(card).unwrap());
+    }
+
+    #[rstest]
+    fn test_examples(#[files("fixtures/*.in")] path: PathBuf) -> Result<()> {
+        Example::load(&path)?.check::<Day04>()
+    }
+}
@@ -0,0 +1,157 @@
+use anyhow::{anyhow, Result};
+use common::{parse, Params, ParseError, Rng, Solution};
+use std::{collections::HashMap, ops::Range};
+
+/// The source ranges of a map, along with the destination range each one is converted to
+pub fn parse_map(input: &str) -> Result<HashMap<Range<usize>, Range<usize>>, ParseError> {
+    let (_, ranges) = parse::header(input)?;
+    ranges
+        .lines()
+        .map(|line| {
+            let [dest_start, src_start, len] = parse::numbers::<usize>(line)?[..] else {
+                return Err(ParseError::new(line, "three numbers"));
+            };
+            match (src_start.checked_add(len), dest_start.checked_add(len)) {
+                (Some(src_end), Some(dest_end)) => Ok((src_start..src_end, dest_start..dest_end)),
+                _ => Err(ParseError::new(line, "ranges that end before usize::MAX")),
+            }
+        })
+        .collect()
+}
+
+pub fn convert(map: &HashMap<Range<usize>, Range<usize>>, value: usize) -> usize {
+    map.iter()
+        .find(|(src, _)| src.contains(&value))
+        .map_or(value, |(src, dest)| dest.start + (value - src.start))
+}
+
+pub struct Almanac {
+    pub seeds: Vec<usize>,
+    pub maps: Vec<HashMap<Range<usize>, Range<usize>>>,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "If You Give A Seed A Fertilizer";
+
+    type Input = Almanac;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let sections = parse::sections(input);
+        let (seeds, maps) = sections
+            .split_first()
+            .ok_or(ParseError::new(input, "the seeds and maps"))?;
+
+        let seeds = parse::numbers(parse::prefixed(seeds.trim(), "seeds:")?)?;
+
+        let maps = maps
+            .iter()
+            .map(|map| parse_map(map))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Almanac { seeds, maps })
+    }
+
+    fn part_one(almanac: &Self::Input, _: &Params) -> Result<usize> {
+        almanac
+            .seeds
+            .iter()
+            .map(|&seed| {
+                almanac
+                    .maps
+                    .iter()
+                    .fold(seed, |value, map| convert(map, value))
+            })
+            .min()
+            .ok_or(anyhow!("No seeds to plant"))
+    }
+
+    fn part_two(_almanac: &Self::Input, _: &Params) -> Result<usize> {
+        todo!(
+            "I somehow managed to lose the solution for part two. It wasn't great either way so \
+               I should give it another try I guess..."
+        )
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        const LIMIT: usize = 1 << 32;
+        const CATEGORIES: [&str; 8] = [
+            "seed",
+            "soil",
+            "fertilizer",
+            "water",
+            "light",
+            "temperature",
+            "humidity",
+            "location",
+        ];
+
+        let seeds = (0..10)
+            .map(|_| {
+                let len = rng.range(1..1 << 28);
+                format!("{} {len}", rng.range(0..LIMIT - len))
+            })
+            .collect::<Vec<_>>();
+        let mut almanac = format!("seeds: {}\n", seeds.join(" "));
+
+        for categories in CATEGORIES.windows(2) {
+            /* Source ranges between consecutive bounds, so that they don't overlap */
+            let mut bounds = (0..=size).map(|_| rng.range(0..LIMIT)).collect::<Vec<_>>();
+            bounds.sort();
+            bounds.dedup();
+            let mut sources = bounds
+                .windows(2)
+                .map(|b| (b[0], b[1] - b[0]))
+                .collect::<Vec<_>>();
+            rng.shuffle(&mut sources);
+
+            almanac += &format!("\n{}-to-{} map:\n", categories[0], categories[1]);
+            for (start, len) in sources {
+                almanac += &format!("{} {start} {len}\n", rng.range(0..LIMIT - len));
+            }
+        }
+
+        Some(almanac)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Example;
+    use rstest::*;
+    use std::path::PathBuf;
+
+    #[rstest]
+    #[case("seed-to-soil map:\n50 98 2\n52 50 48",
+           vec![(98..100, 50..52), (50..98, 52..100)])]
+    #[case("soil-to-fertilizer map:\n0 15 37\n37 52 2\n39 0 15",
+           vec![(15..52, 0..37), (52..54, 37..39), (0..15, 39..54)])]
+    #[case("fertilizer-to-water map:\n49 53 8\n0 11 42\n42 0 7\n57 7 4\n",
+           vec![(53..61, 49..57), (11..53, 0..42), (0..7, 42..49), (7..11, 57..61)])]
+    fn test_parse_map(#[case] input: &str, #[case] expected: Vec<(Range<usize>, Range<usize>)>) {
+        let expected = expected
+            .into_iter()
+            .collect::<HashMap<Range<usize>, Range<usize>>>();
+        assert_eq!(expected, parse_map(input).unwrap());
+    }
+
+    #[rstest]
+    #[case("seed-to-soil map:\n50 18446744073709551615 2")]
+    #[case("seed-to-soil map:\n18446744073709551615 50 2")]
+    fn test_map_overflow(#[case] input: &str) {
+        assert_eq!(
+            parse_map(input).unwrap_err().expected(),
+            "ranges that end before usize::MAX"
+        );
+    }
+
+    #[rstest]
+    fn test_examples(#[files("fixtures/*.in")] path: PathBuf) -> Result<()> {
+        Example::load(&path)?.check::<Day05>()
+    }
+}
@@ -0,0 +1,181 @@
+use anyhow::{bail, Result};
+use common::{parse, Params, ParseError, Rng, Solution};
+use std::iter::zip;
+
+/* Let t = the time limit of a rance
+ * and p = the distance record
+ *
+ *  Since the 'acceleration' of charging
+ *  the boat is a = 1 mm/ms^2,
+ *  the final velocity v is going
+ *  to be equal to the charging time
+ *  t_c.
+ *
+ *  Let f: N -> N, f(t_c) = (t - t_c) * v
+ *                 f(v)   = (t - v  ) * v
+ *                        = tv - v^2
+ *                        = -v^2 + tv
+ *  be the function that gives the distance
+ *  traveled for a charging time of t_c.
+ *
+ *  The winning condition is
+ *      f(v)          > p
+ *      -v^2 + tv     > p
+ *      -v^2 + tv - p > 0
+ *  which implies that
+ *      v \in [0.5 * (t - sqrt(t - 4p)), 0.5 * (t + sqrt(t - 4p))] \intersect N
+ *  or, a more useful form
+ *      ⌈0.5 * (t - sqrt(t - 4p))⌉ < v < ⌊0.5 * (t + sqrt(t - 4p))⌋
+ */
+
+/// How many charging times beat the `distance` record within `time`
+pub fn ways_to_win(time: u64, distance: u64) -> u64 {
+    let wins = |v: u64| v * (time - v) > distance;
+    let (t, p) = (time as f64, distance as f64);
+
+    /* The floating point root can be off by one (or more, for big numbers) either way, so nudge it
+     * onto the first winning time. When the record can't be beaten, the discriminant is negative. */
+    let mut lower = ((0.5 * (t - (t * t - 4.0 * p).max(0.0).sqrt())).floor() as u64).min(time / 2);
+    while lower > 0 && wins(lower - 1) {
+        lower -= 1;
+    }
+    while lower <= time / 2 && !wins(lower) {
+        lower += 1;
+    }
+
+    /* f is symmetric around t / 2, where it peaks */
+    if lower > time / 2 {
+        0
+    } else {
+        time - 2 * lower + 1
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Wait For It";
+
+    type Input = Vec<(u64, u64)>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (time_line, distance_line) = input.split_once("\n").ok_or(ParseError::new(
+            input,
+            "a line of times and a line of distances",
+        ))?;
+
+        let times = time_line.split_whitespace().skip(1).collect::<Vec<_>>();
+        let distances = distance_line.split_whitespace().skip(1).collect::<Vec<_>>();
+        /* Zipping them would quietly drop the races missing a time or a distance */
+        if times.len() != distances.len() {
+            bail!(ParseError::new(
+                distance_line.trim_end(),
+                format!("{} distances, one per time", times.len())
+            ));
+        }
+
+        zip(times, distances)
+            .map(|(t, d)| Ok((parse::number(t)?, parse::number(d)?)))
+            .collect()
+    }
+
+    fn part_one(races: &Self::Input, _: &Params) -> Result<u64> {
+        Ok(races
+            .iter()
+            .map(|&(time, distance)| ways_to_win(time, distance))
+            .product())
+    }
+
+    fn part_two(races: &Self::Input, _: &Params) -> Result<u64> {
+        /* The kerning is bad, so the numbers on each line are actually a single number */
+        let time = races
+            .iter()
+            .map(|(t, _)| t.to_string())
+            .collect::<String>()
+            .parse::<u64>()?;
+
+        let distance = races
+            .iter()
+            .map(|(_, d)| d.to_string())
+            .collect::<String>()
+            .parse::<u64>()?;
+
+        Ok(ways_to_win(time, distance))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        /* Part two reads all the races as a single one, whose record has to fit in a u64 */
+        let races = (0..size.clamp(1, 4))
+            .map(|_| {
+                let time = rng.range(7..100);
+                (time, rng.range(time..time * time / 4))
+            })
+            .collect::<Vec<_>>();
+
+        let line = |label, numbers: Vec<usize>| {
+            let numbers = numbers
+                .iter()
+                .map(|n| format!("{n:>6}"))
+                .collect::<String>();
+            format!("{label}:{numbers}\n")
+        };
+        Some(
+            line("Time", races.iter().map(|&(t, _)| t).collect())
+                + &line("Distance", races.iter().map(|&(_, d)| d).collect()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Example;
+    use proptest::prelude::*;
+    use rstest::*;
+    use std::path::PathBuf;
+
+    #[rstest]
+    fn test_examples(#[files("fixtures/*.in")] path: PathBuf) -> Result<()> {
+        Example::load(&path)?.check::<Day06>()
+    }
+
+    #[test]
+    fn test_missing_distance() -> Result<()> {
+        let error = Day06::parse("Time: 7 15 30\nDistance: 9 40\n").unwrap_err();
+        assert_eq!(
+            error.downcast::<ParseError>()?.expected(),
+            "3 distances, one per time"
+        );
+        Ok(())
+    }
+
+    /// The number of ways to win a race, trying every charging time
+    fn naive_ways_to_win(time: u64, distance: u64) -> u64 {
+        (0..=time).filter(|v| v * (time - v) > distance).count() as u64
+    }
+
+    proptest! {
+        #[test]
+        fn test_part_one_formula(races in prop::collection::vec((1u64..100, 0u64..2500), 1..4)) {
+            let expected = races
+                .iter()
+                .map(|&(time, distance)| naive_ways_to_win(time, distance))
+                .product::<u64>();
+            prop_assert_eq!(Day06::part_one(&races, &Params::defaults(&[])).unwrap(), expected);
+        }
+
+        #[test]
+        fn test_part_two_search(races in prop::collection::vec((1u64..30, 0u64..200), 1..3)) {
+            let concatenate = |numbers: Vec<u64>| {
+                numbers.iter().map(u64::to_string).collect::<String>().parse::<u64>().unwrap()
+            };
+            let time = concatenate(races.iter().map(|&(t, _)| t).collect());
+            let distance = concatenate(races.iter().map(|&(_, d)| d).collect());
+            let ways = Day06::part_two(&races, &Params::defaults(&[])).unwrap();
+            prop_assert_eq!(ways, naive_ways_to_win(time, distance));
+        }
+    }
+}
@@ -1,8 +1,9 @@
-use anyhow::{anyhow, Result};
+use anyhow::Result;
+use common::ParseError;
 use itertools::Itertools;
 use std::{cmp::Ordering, str::FromStr};
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Hand {
     FiveKind(u32),
     FourKind(u32),
@@ -49,27 +50,30 @@ impl Ord for Hand {
     }
 }
 
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl FromStr for Hand {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if s.len() > 5 {
-            return Err(anyhow!("Hand too long"));
+            return Err(ParseError::new(s, "a hand of at most 5 cards").into());
         }
 
         let digits = s
-            .chars()
-            .map(|c| match c {
-                character if character.is_digit(10) => character
-                    .to_digit(10)
-                    .map(|d| d - 2)
-                    .ok_or(anyhow!("Invalid card label")),
+            .char_indices()
+            .map(|(i, c)| match c {
+                digit @ '2'..='9' => Ok(digit as u32 - '2' as u32),
                 'T' => Ok(8),
                 'J' => Ok(9),
                 'Q' => Ok(10),
                 'K' => Ok(11),
                 'A' => Ok(12),
-                _ => Err(anyhow!("Invalid card label")),
+                _ => Err(ParseError::new(&s[i..i + c.len_utf8()], "a card label").into()),
             })
             .collect::<Result<Vec<u32>>>()?;
 
@@ -119,6 +123,15 @@ mod tests {
         assert_eq!(h.parse::<Hand>().unwrap(), expected);
     }
 
+    #[rstest]
+    #[case("23451", "1")]
+    #[case("0", "0")]
+    #[case("AAé", "é")]
+    fn test_hand_parse_error(#[case] h: &str, #[case] found: &str) {
+        let error = h.parse::<Hand>().unwrap_err();
+        assert_eq!(error.downcast_ref::<ParseError>().unwrap().found(), found);
+    }
+
     #[rstest]
     #[case("KK677", "KTJJT", Ordering::Greater)]
     #[case("T55J5", "QQQJA", Ordering::Less)]
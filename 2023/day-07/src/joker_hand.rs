@@ -1,8 +1,9 @@
-use anyhow::{anyhow, Result};
+use anyhow::Result;
+use common::ParseError;
 use itertools::Itertools;
 use std::{cmp::Ordering, str::FromStr};
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum JokerHand {
     FiveKind(u32),
     FourKind(u32),
@@ -49,27 +50,30 @@ impl Ord for JokerHand {
     }
 }
 
+impl PartialOrd for JokerHand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl FromStr for JokerHand {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if s.len() > 5 {
-            return Err(anyhow!("Hand too long"));
+            return Err(ParseError::new(s, "a hand of at most 5 cards").into());
         }
 
         let digits = s
-            .chars()
-            .map(|c| match c {
+            .char_indices()
+            .map(|(i, c)| match c {
                 'J' => Ok(0),
-                character if character.is_digit(10) => character
-                    .to_digit(10)
-                    .map(|d| d - 1)
-                    .ok_or(anyhow!("Invalid card label")),
+                digit @ '2'..='9' => Ok(digit as u32 - '1' as u32),
                 'T' => Ok(9),
                 'Q' => Ok(10),
                 'K' => Ok(11),
                 'A' => Ok(12),
-                _ => Err(anyhow!("Invalid card label")),
+                _ => Err(ParseError::new(&s[i..i + c.len_utf8()], "a card label").into()),
             })
             .collect::<Result<Vec<u32>>>()?;
 
@@ -83,9 +87,7 @@ impl FromStr for JokerHand {
         freq.iter().enumerate().count();
 
         let freq_counts = (0..=5)
-            .map(|digit_freq| freq[1..].into_iter().filter(|&&e| e == digit_freq).count())
-            .enumerate()
-            .map(|(_, e)| e)
+            .map(|digit_freq| freq[1..].iter().filter(|&&e| e == digit_freq).count())
             .collect_vec();
 
         if freq_counts[5] == 1 {
@@ -132,6 +134,14 @@ mod tests {
         assert_eq!(h.parse::<JokerHand>().unwrap(), expected);
     }
 
+    #[rstest]
+    #[case("JJ1", "1")]
+    #[case("0", "0")]
+    fn test_hand_parse_error(#[case] h: &str, #[case] found: &str) {
+        let error = h.parse::<JokerHand>().unwrap_err();
+        assert_eq!(error.downcast_ref::<ParseError>().unwrap().found(), found);
+    }
+
     #[rstest]
     #[case("KK677", "KTJJT", Ordering::Less)]
     #[case("T55J5", "QQQJA", Ordering::Less)]
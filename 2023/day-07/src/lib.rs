@@ -0,0 +1,72 @@
+use anyhow::Result;
+use common::{parse, Params, Rng, Solution};
+use itertools::Itertools;
+
+mod hand;
+mod joker_hand;
+
+pub use hand::Hand;
+pub use joker_hand::JokerHand;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Camel Cards";
+
+    type Input = Vec<(Hand, JokerHand, usize)>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .map(|line| {
+                let (hand, bid) = parse::split_once(line, " ")?;
+
+                Ok((hand.parse()?, hand.parse()?, parse::number(bid)?))
+            })
+            .collect()
+    }
+
+    fn part_one(hands: &Self::Input, _: &Params) -> Result<usize> {
+        Ok(hands
+            .iter()
+            .map(|&(hand, _, bid)| (hand, bid))
+            .sorted_by(|(h1, _), (h2, _)| h1.cmp(h2))
+            .enumerate()
+            .map(|(i, (_, b))| (i + 1) * b)
+            .sum())
+    }
+
+    fn part_two(hands: &Self::Input, _: &Params) -> Result<usize> {
+        Ok(hands
+            .iter()
+            .map(|&(_, hand, bid)| (hand, bid))
+            .sorted_by(|(h1, _), (h2, _)| h1.cmp(h2))
+            .enumerate()
+            .map(|(i, (_, b))| (i + 1) * b)
+            .sum())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let cards = "AKQJT98765432".chars().collect::<Vec<_>>();
+        Some(rng.lines(size, |rng, _| {
+            let hand = (0..5).map(|_| *rng.pick(&cards)).collect::<String>();
+            format!("{hand} {}", rng.range(1..1000))
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Example;
+    use rstest::*;
+    use std::path::PathBuf;
+
+    #[rstest]
+    fn test_examples(#[files("fixtures/*.in")] path: PathBuf) -> Result<()> {
+        Example::load(&path)?.check::<Day07>()
+    }
+}
@@ -0,0 +1,204 @@
+use anyhow::{anyhow, bail, Result};
+use common::{parse, Assumption, Params, ParseError, Solution};
+use itertools::*;
+use std::collections::BTreeMap;
+
+#[derive(Debug)]
+pub struct Network {
+    pub directions: String,
+    pub nodes: BTreeMap<String, (String, String)>,
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Haunted Wasteland";
+
+    type Input = Network;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let [directions, nodes] = parse::sections(input)[..] else {
+            bail!(ParseError::new(
+                input,
+                "directions and nodes separated by a blank line"
+            ));
+        };
+
+        let directions = directions.trim();
+        if let Some(i) = directions.find(|ch| ch != 'L' && ch != 'R') {
+            bail!(ParseError::new(&directions[i..i + 1], "`L` or `R`"));
+        }
+
+        let nodes = nodes
+            .lines()
+            .map(|line| {
+                let (src, dest) = parse::split_once(line.trim(), " = ")?;
+                let [left, right] = parse::fields(parse::delimited(dest, '(', ')')?, ", ")?;
+
+                Ok((src, (left, right)))
+            })
+            .collect::<Result<BTreeMap<_, _>, ParseError>>()?;
+
+        if let Some(dest) = nodes
+            .values()
+            .flat_map(|&(left, right)| [left, right])
+            .find(|dest| !nodes.contains_key(dest))
+        {
+            bail!(ParseError::new(dest, "a node defined in the network"));
+        }
+
+        Ok(Network {
+            directions: directions.to_owned(),
+            nodes: nodes
+                .into_iter()
+                .map(|(src, (left, right))| (src.to_owned(), (left.to_owned(), right.to_owned())))
+                .collect(),
+        })
+    }
+
+    /* The example of part two has no `AAA` node, so it's only missing once part one needs it */
+    fn part_one(network: &Self::Input, _: &Params) -> Result<usize> {
+        if !network.nodes.contains_key("AAA") {
+            bail!("There is no `AAA` node");
+        }
+
+        let (steps, _) = walk(network, "AAA", 0, |node| node == "ZZZ")
+            .ok_or(anyhow!("`ZZZ` can't be reached from `AAA`"))?;
+        Ok(steps)
+    }
+
+    fn part_two(network: &Self::Input, _: &Params) -> Result<usize> {
+        network
+            .nodes
+            .keys()
+            .filter(|node| node.ends_with('A'))
+            .map(|start| {
+                walk(network, start, 0, is_z)
+                    .map(|(steps, _)| steps)
+                    .ok_or(anyhow!("The ghost from `{start}` never reaches a `Z` node"))
+            })
+            .fold_ok(1, num::integer::lcm)
+    }
+
+    /* Part two only counts the steps to the first `Z` node of each ghost, and takes their LCM.
+     * That's only the step they're all on a `Z` node together if every ghost then loops back to
+     * that node in as many steps again, which the puzzle doesn't promise. */
+    fn assumptions(network: &Self::Input) -> Vec<Assumption> {
+        let loops = network
+            .nodes
+            .keys()
+            .filter(|node| node.ends_with('A'))
+            .try_for_each(|start| {
+                let (steps, end) = walk(network, start, 0, is_z)
+                    .ok_or(format!("The ghost from `{start}` never reaches a `Z` node"))?;
+                match walk(network, end, steps, is_z) {
+                    Some((again, next)) if next == end && again == steps => Ok(()),
+                    Some((again, next)) => Err(format!(
+                        "The ghost from `{start}` reaches `{end}` after {steps} steps, then \
+                         `{next}` after {again} more"
+                    )),
+                    None => Err(format!(
+                        "The ghost from `{start}` never reaches a `Z` node again after `{end}`"
+                    )),
+                }
+            });
+
+        vec![Assumption::new(
+            "Every ghost gets back to its first `Z` node in as many steps as it took to get there, \
+             without meeting another `Z` node on the way",
+            loops,
+        )]
+    }
+
+    /* No `generate`: a random network almost never has ghosts that loop back to their first `Z`
+     * node, and one built to would only exercise the LCM, not the walk */
+}
+
+fn is_z(node: &str) -> bool {
+    node.ends_with('Z')
+}
+
+/// How many steps it takes from `start`, `taken` steps into the directions, to the next node that
+/// is an `end`, and which one it is. `None` if it's never reached, or if a node leads nowhere.
+fn walk<'a>(
+    network: &'a Network,
+    start: &'a str,
+    taken: usize,
+    end: impl Fn(&str) -> bool,
+) -> Option<(usize, &'a str)> {
+    let directions = network.directions.as_bytes();
+    /* Past that many steps, some node was reached twice at the same point of the directions */
+    let limit = network.nodes.len() * directions.len();
+
+    let mut node = start;
+    for steps in 1..=limit {
+        let (left, right) = network.nodes.get(node)?;
+        node = match directions[(taken + steps - 1) % directions.len()] {
+            b'L' => left,
+            _ => right,
+        };
+        if end(node) {
+            return Some((steps, node));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Example;
+    use indoc::indoc;
+    use rstest::*;
+    use std::path::{Path, PathBuf};
+
+    #[rstest]
+    fn test_examples(#[files("fixtures/*.in")] path: PathBuf) -> Result<()> {
+        Example::load(&path)?.check::<Day08>()
+    }
+
+    #[test]
+    fn test_malformed_network() -> Result<()> {
+        let error = Day08::parse("RL\n\nAAA = (BBB, CCC)\nBBB = (DDD, EEE)\n").unwrap_err();
+        assert_eq!(
+            error.downcast::<ParseError>()?.found(),
+            "CCC",
+            "Nodes can't lead to undefined ones"
+        );
+
+        let params = Params::defaults(Day08::PARAMS);
+        let network = Day08::parse("L\n\nAAA = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)\n")?;
+        assert!(Day08::part_one(&network, &params).is_err());
+        assert!(Day08::part_two(&network, &params).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_assumptions() -> Result<()> {
+        let ghosts = Example::load(Path::new("fixtures/ghosts.in"))?;
+        let network = Day08::parse(&ghosts.input)?;
+        assert!(Day08::assumptions(&network).iter().all(Assumption::holds));
+
+        /* From 11A, the first `Z` node is 2 steps away and the other one 1 step further */
+        let network = Day08::parse(indoc! {"
+            LR
+
+            11A = (11B, XXX)
+            11B = (XXX, 11Z)
+            11Z = (22Z, XXX)
+            22Z = (11B, 11B)
+            XXX = (XXX, XXX)
+        "})?;
+        let [assumption] = &Day08::assumptions(&network)[..] else {
+            panic!("Day 08 has a single assumption");
+        };
+        assert_eq!(
+            assumption.violation.as_deref(),
+            Some("The ghost from `11A` reaches `11Z` after 2 steps, then `22Z` after 1 more")
+        );
+        Ok(())
+    }
+}
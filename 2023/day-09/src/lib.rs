@@ -0,0 +1,110 @@
+use anyhow::{bail, Result};
+use common::{parse, Params, ParseError, Rng, Solution};
+use itertools::*;
+
+/// The value that comes after `sequence`
+pub fn extrapolate_sequence(sequence: &[i32]) -> i32 {
+    let mut sequence = sequence.to_owned();
+    let mut terms = vec![sequence[sequence.len() - 1]];
+    loop {
+        sequence = sequence
+            .iter()
+            .tuple_windows()
+            .map(|(a, b)| b - a)
+            .collect_vec();
+
+        terms.push(sequence[sequence.len() - 1]);
+
+        if sequence.iter().all_equal() {
+            break;
+        }
+    }
+
+    terms.iter().sum()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Mirage Maintenance";
+
+    type Input = Vec<Vec<i32>>;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .map(|line| {
+                let sequence = line
+                    .split_whitespace()
+                    .map(parse::number)
+                    .collect::<Result<Vec<i32>, _>>()?;
+
+                /* Extrapolating takes at least one difference between values */
+                if sequence.len() < 2 {
+                    bail!(ParseError::new(line, "a sequence of at least two numbers"));
+                }
+                Ok(sequence)
+            })
+            .collect()
+    }
+
+    fn part_one(histories: &Self::Input, _: &Params) -> Result<i32> {
+        Ok(histories.iter().map(|v| extrapolate_sequence(v)).sum())
+    }
+
+    fn part_two(histories: &Self::Input, _: &Params) -> Result<i32> {
+        Ok(histories
+            .iter()
+            .map(|v| v.iter().rev().copied().collect_vec())
+            .map(|v| extrapolate_sequence(&v))
+            .sum())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        /* Polynomials, given by their first value and differences, so that the differences do end
+         * up all equal. Small degrees and coefficients keep every value in an i32. */
+        Some(rng.lines(size, |rng, _| {
+            let mut differences = (0..rng.range(1..7))
+                .map(|_| rng.range(0..21) as i32 - 10)
+                .collect_vec();
+            (0..21)
+                .map(|_| {
+                    let value = differences[0];
+                    for k in 1..differences.len() {
+                        differences[k - 1] += differences[k];
+                    }
+                    value
+                })
+                .join(" ")
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Example;
+    use rstest::*;
+    use std::path::PathBuf;
+
+    #[rstest]
+    fn test_examples(#[files("fixtures/*.in")] path: PathBuf) -> Result<()> {
+        Example::load(&path)?.check::<Day09>()
+    }
+
+    #[rstest]
+    #[case("1")]
+    #[case("0 3 6\n1\n")]
+    #[case("0 3 6\n\n1 3 6\n")]
+    fn test_short_sequence(#[case] input: &str) -> Result<()> {
+        let error = Day09::parse(input).unwrap_err();
+        assert_eq!(
+            error.downcast::<ParseError>()?.expected(),
+            "a sequence of at least two numbers"
+        );
+        Ok(())
+    }
+}
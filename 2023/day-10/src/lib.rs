@@ -0,0 +1,277 @@
+use anyhow::{anyhow, Result};
+use common::{Params, Rng, Solution};
+use grid::{Direction, Grid, Point};
+use itertools::*;
+use std::cmp::Ordering;
+
+/// The pipes and the two directions each of them connects, in clockwise order
+const PIPES: [(char, [Direction; 2]); 6] = [
+    ('|', [Direction::North, Direction::South]),
+    ('-', [Direction::East, Direction::West]),
+    ('L', [Direction::North, Direction::East]),
+    ('J', [Direction::North, Direction::West]),
+    ('7', [Direction::South, Direction::West]),
+    ('F', [Direction::East, Direction::South]),
+];
+
+pub fn connections(tile: char) -> &'static [Direction] {
+    PIPES
+        .iter()
+        .find(|(pipe, _)| *pipe == tile)
+        .map_or(&[], |(_, directions)| directions)
+}
+
+/* Walked one tile at a time rather than recursively, as loops in real inputs are long enough to
+ * overflow the stack */
+fn find_cycle(tiles: &Grid<char>, start: Point) -> Result<Vec<Point>> {
+    let mut cycle = vec![start];
+    let (mut current_tile, mut direction) = (start, connections(tiles[start])[0]);
+
+    loop {
+        /* Every pipe connects to two others at most, so a walk that doesn't get back to the start
+         * runs into a pipe that doesn't connect back */
+        let next_tile = tiles
+            .step(current_tile, direction)
+            .filter(|&next_tile| connections(tiles[next_tile]).contains(&direction.reverse()))
+            .ok_or(anyhow!(
+                "The loop doesn't close: nothing connects to {current_tile} going {direction:?}"
+            ))?;
+        if next_tile == start {
+            return Ok(cycle);
+        }
+
+        cycle.push(next_tile);
+        direction = *connections(tiles[next_tile])
+            .iter()
+            .find(|&&next_direction| next_direction != direction.reverse())
+            .expect("Pipes connect two directions");
+        current_tile = next_tile;
+    }
+}
+
+pub fn get_cycle(mut tiles: Grid<char>) -> Result<Vec<Point>> {
+    let start = tiles
+        .position(|&c| c == 'S')
+        .ok_or(anyhow!("There is no starting tile"))?;
+
+    // The start connects to the neighbours that have a pipe pointing back at it
+    let directions = Direction::ALL
+        .into_iter()
+        .filter(|&direction| {
+            tiles
+                .step(start, direction)
+                .is_some_and(|neighbor| connections(tiles[neighbor]).contains(&direction.reverse()))
+        })
+        .collect_vec();
+
+    let (s, _) = PIPES
+        .iter()
+        .find(|(_, connected)| connected[..] == directions[..])
+        .ok_or(anyhow!(
+            "The starting tile should connect to exactly two pipes"
+        ))?;
+
+    tiles[start] = *s;
+
+    find_cycle(&tiles, start)
+}
+
+pub fn shoelace(points: &[Point]) -> usize {
+    let n = points.len();
+
+    let points = points
+        .iter()
+        .map(|point| (point.row as isize, point.col as isize))
+        .collect_vec();
+
+    ((0..n)
+        .map(|i| (points[i].0 + points[(i + 1) % n].0) * (points[i].1 - points[(i + 1) % n].1))
+        .sum::<isize>()
+        / 2)
+    .unsigned_abs()
+}
+
+/* A loop shaped like a histogram: along the bottom row from the left, up the last column, then
+ * back left along the top of each column (at height `tops[col - 1]`) and down the first one. */
+fn histogram_loop(width: usize, bottom: usize, tops: &[usize]) -> Vec<Point> {
+    let mut points = vec![];
+    let mut cursor = Point::new(bottom, 0);
+    let mut walk_to = |row: usize, col: usize| {
+        while cursor != Point::new(row, col) {
+            points.push(cursor);
+            if cursor.row != row {
+                cursor.row = if cursor.row < row {
+                    cursor.row + 1
+                } else {
+                    cursor.row - 1
+                };
+            } else {
+                cursor.col = if cursor.col < col {
+                    cursor.col + 1
+                } else {
+                    cursor.col - 1
+                };
+            }
+        }
+    };
+
+    walk_to(bottom, width);
+    for col in (1..=width).rev() {
+        walk_to(tops[col - 1], col);
+        walk_to(tops[col - 1], col - 1);
+    }
+    walk_to(bottom, 0);
+
+    points
+}
+
+/// The pipe that connects `point` to both of its neighbours along the loop
+fn pipe(point: Point, neighbours: [Point; 2]) -> char {
+    let directions = neighbours.map(|neighbour| {
+        match (neighbour.row.cmp(&point.row), neighbour.col.cmp(&point.col)) {
+            (Ordering::Less, _) => Direction::North,
+            (Ordering::Greater, _) => Direction::South,
+            (_, Ordering::Greater) => Direction::East,
+            _ => Direction::West,
+        }
+    });
+
+    PIPES
+        .iter()
+        .find(|(_, connected)| directions.iter().all(|d| connected.contains(d)))
+        .map(|(pipe, _)| *pipe)
+        .expect("Two different directions make a pipe")
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Pipe Maze";
+
+    type Input = Grid<char>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::from_rows(
+            input
+                .lines()
+                .map(|line| line.trim().chars().collect())
+                .collect(),
+        )
+    }
+
+    fn part_one(tiles: &Self::Input, _: &Params) -> Result<usize> {
+        Ok(get_cycle(tiles.clone())?.len() / 2)
+    }
+
+    fn part_two(tiles: &Self::Input, _: &Params) -> Result<usize> {
+        let cycle = get_cycle(tiles.clone())?;
+
+        Ok(shoelace(&cycle) + 1 - cycle.len() / 2)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let (width, bottom) = (size.max(1), size.max(1));
+        let tops = (0..width).map(|_| rng.range(0..bottom)).collect_vec();
+        let points = histogram_loop(width, bottom, &tops);
+        let n = points.len();
+        let start = points[rng.range(0..n)];
+
+        /* Pipes off the loop are only noise, except next to the start where they would look like
+         * more than two pipes connect to it */
+        let mut tiles = Grid::filled(width + 1, bottom + 1, '.');
+        let next_to_start = tiles.neighbours4(start).collect_vec();
+        for point in tiles.points().collect_vec() {
+            if !next_to_start.contains(&point) && rng.one_in(3) {
+                tiles[point] = rng.pick(&PIPES).0;
+            }
+        }
+        for (i, &point) in points.iter().enumerate() {
+            tiles[point] = pipe(point, [points[(i + n - 1) % n], points[(i + 1) % n]]);
+        }
+        tiles[start] = 'S';
+
+        Some(format!("{tiles}\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Example;
+    use proptest::prelude::*;
+    use rstest::*;
+    use std::{collections::BTreeSet, path::PathBuf};
+
+    #[rstest]
+    fn test_examples(#[files("fixtures/*.in")] path: PathBuf) -> Result<()> {
+        Example::load(&path)?.check::<Day10>()
+    }
+
+    #[rstest]
+    #[case("S-7\n|.|\nL--\n")]
+    #[case("S-7\n|.|\nL-|\n")]
+    #[case("S-7\n|.-\nL-J\n")]
+    fn test_open_loop(#[case] input: &str) -> Result<()> {
+        let tiles = Day10::parse(input)?;
+        assert!(Day10::part_one(&tiles, &Params::defaults(&[])).is_err());
+        Ok(())
+    }
+
+    /// Counts the tiles inside the loop by casting a ray to the left of each of them, and counting
+    /// how many times it crosses the loop
+    fn naive_enclosed(tiles: &[Vec<char>], on_loop: &BTreeSet<Point>) -> usize {
+        (0..tiles.len())
+            .flat_map(|row| (0..tiles[row].len()).map(move |col| Point::new(row, col)))
+            .filter(|point| !on_loop.contains(point))
+            .filter(|point| {
+                let crossings = (0..point.col)
+                    .map(|col| Point::new(point.row, col))
+                    .filter(|left| {
+                        on_loop.contains(left)
+                            && matches!(tiles[left.row][left.col], '|' | 'L' | 'J')
+                    })
+                    .count();
+                crossings % 2 == 1
+            })
+            .count()
+    }
+
+    fn loops() -> impl Strategy<Value = (usize, usize, Vec<usize>, usize)> {
+        (1usize..8, 1usize..8).prop_flat_map(|(width, bottom)| {
+            let length = 2 * width + 2 * bottom;
+            (
+                Just(width),
+                Just(bottom),
+                prop::collection::vec(0..bottom, width),
+                0..length,
+            )
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_shoelace_and_pick((width, bottom, tops, start) in loops()) {
+            let points = histogram_loop(width, bottom, &tops);
+            let n = points.len();
+
+            let mut tiles = vec![vec!['.'; width + 1]; bottom + 1];
+            for (i, &point) in points.iter().enumerate() {
+                tiles[point.row][point.col] = pipe(point, [points[(i + n - 1) % n], points[(i + 1) % n]]);
+            }
+            let on_loop = points.iter().copied().collect::<BTreeSet<_>>();
+            let expected = naive_enclosed(&tiles, &on_loop);
+
+            let start = points[start % n];
+            tiles[start.row][start.col] = 'S';
+            let input = tiles.iter().map(|row| row.iter().collect::<String>() + "\n").collect::<String>();
+
+            let params = Params::defaults(&[]);
+            let tiles = Day10::parse(&input).unwrap();
+            prop_assert_eq!(Day10::part_one(&tiles, &params).unwrap(), n / 2);
+            prop_assert_eq!(Day10::part_two(&tiles, &params).unwrap(), expected);
+        }
+    }
+}
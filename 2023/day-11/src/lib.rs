@@ -0,0 +1,168 @@
+use anyhow::Result;
+use common::{Param, Params, Rng, Solution};
+use grid::{Grid, GridTile, Point};
+use itertools::*;
+
+/*
+* A bit of an explanation for me in the future.
+* To deal with arbitrary (very large) scaling
+* factors, I represent the universe as a grid of `Tile`s, where each Tile can be either a `Galaxy`
+* or empty `Space`. Each variant stores it's "length".
+* I first parse the input into a 2d-array of `Tile`s, then I expand it by updating each line and
+* column that is comprised only of `Space`s with `Space`s with length == scaling_factor.
+*
+* Take for instance the following case and its representation:
+* .#.      Space(1)  Galaxy(1) Space(1)
+* ... ---> Space(1)  Space(1)  Space(1) <- This row should be expanded
+* #..      Galaxy(1) Space(1)  Space(1)
+*                                ^-------- This column should also be expanded
+*
+* Which, after expanding with a scaling factor of 2 becomes:
+* Space(1)  Galaxy(1) Space(2)      .#..
+* Space(2)  Space(2)  Space(2) ---> .... <-- These rows were expanded
+* Galaxy(1) Space(1)  Space(2)      .... <-^
+*                                   #...
+*                                     ^^---- These columns were expanded
+*
+* The distance between the galaxies at coordinates (y1, x1) and (y2, x2) respectively is the sum dx + dy,
+* where dx = sum(tiles between x1 and x2) and dy = sum(tiles between y1 and y2).
+*/
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone, GridTile)]
+pub enum Tile {
+    #[tile('#', 1)]
+    Galaxy(usize),
+    #[tile('.', 1)]
+    Space(usize),
+}
+
+pub fn expand_universe(universe: &mut Grid<Tile>, factor: usize) {
+    for i in 0..universe.height() {
+        let row = universe.row_mut(i);
+        if row.iter().all(|tile| matches!(tile, Tile::Space(_))) {
+            for tile in row.iter_mut() {
+                *tile = Tile::Space(factor);
+            }
+        }
+    }
+
+    for j in 0..universe.width() {
+        if universe
+            .column(j)
+            .all(|tile| matches!(tile, Tile::Space(_)))
+        {
+            for i in 0..universe.height() {
+                universe[Point::new(i, j)] = Tile::Space(factor);
+            }
+        }
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Cosmic Expansion";
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "factor_one",
+            default: "2",
+            description: "How many times larger empty rows and columns become in part one",
+        },
+        Param {
+            name: "factor_two",
+            default: "1000000",
+            description: "How many times larger empty rows and columns become in part two",
+        },
+    ];
+
+    type Input = Grid<Tile>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part_one(universe: &Self::Input, params: &Params) -> Result<usize> {
+        Ok(solve(universe, params.get("factor_one")?))
+    }
+
+    fn part_two(universe: &Self::Input, params: &Params) -> Result<usize> {
+        Ok(solve(universe, params.get("factor_two")?))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(rng.grid(size, size, |rng| if rng.one_in(40) { '#' } else { '.' }))
+    }
+}
+
+/// The sum of the distances between every pair of galaxies, once empty space is `factor` times larger
+pub fn solve(universe: &Grid<Tile>, factor: usize) -> usize {
+    let mut universe = universe.clone();
+
+    // Maybe there is a way to avoid mutating here...
+    expand_universe(&mut universe, factor);
+
+    let galaxies = universe
+        .iter()
+        .filter(|(_, tile)| matches!(tile, Tile::Galaxy(_)))
+        .map(|(point, _)| (point.row, point.col))
+        .collect_vec();
+
+    galaxies
+        .iter()
+        .tuple_combinations()
+        .map(|(&(y1, x1), &(y2, x2))| {
+            let dx: usize = universe.row(y1)[x1.min(x2)..x1.max(x2)]
+                .iter()
+                .map(|tile| match tile {
+                    Tile::Space(n) => n,
+                    Tile::Galaxy(n) => n,
+                })
+                .sum();
+
+            let dy: usize = (y1.min(y2)..y1.max(y2))
+                .map(|y| match universe[Point::new(y, x1)] {
+                    Tile::Galaxy(n) => n,
+                    Tile::Space(n) => n,
+                })
+                .sum();
+
+            dx + dy
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Example;
+    use indoc::indoc;
+    use rstest::*;
+    use std::path::PathBuf;
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.in");
+
+    #[rstest]
+    #[case(indoc! {"
+        .#.
+        ...
+        #..
+    "}, 2, 4)]
+    #[case(EXAMPLE, 2, 374)]
+    #[case(EXAMPLE, 10, 1030)]
+    fn test_solution(
+        #[case] input: &str,
+        #[case] scaling_factor: usize,
+        #[case] expected: usize,
+    ) -> Result<()> {
+        assert_eq!(expected, solve(&Day11::parse(input)?, scaling_factor));
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_examples(#[files("fixtures/*.in")] path: PathBuf) -> Result<()> {
+        Example::load(&path)?.check::<Day11>()
+    }
+}
@@ -0,0 +1,264 @@
+use anyhow::Result;
+use common::{parse, Params, ParseError, Rng, Solution};
+use grid::GridTile;
+use itertools::repeat_n;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/* Taken from https://github.com/andypymont/advent2023-rust/blob/main/src/bin/12.rs
+* This is what I get for not learning DP... */
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Hot Springs";
+
+    type Input = Vec<Record>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.lines().map(str::parse).collect()
+    }
+
+    fn part_one(records: &Self::Input, _: &Params) -> Result<usize> {
+        Ok(records.iter().map(Record::arrangements).sum())
+    }
+
+    fn part_two(records: &Self::Input, _: &Params) -> Result<usize> {
+        Ok(records
+            .iter()
+            .map(|record| record.clone().unfold().arrangements())
+            .sum())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(rng.lines(size, |rng, _| {
+            let len = rng.range(4..17);
+            let mut springs = (0..len)
+                .map(|_| if rng.one_in(2) { '#' } else { '.' })
+                .collect::<Vec<_>>();
+            /* Every record has at least one group */
+            springs[rng.range(0..len)] = '#';
+
+            let groups = springs
+                .split(|&spring| spring == '.')
+                .filter(|group| !group.is_empty())
+                .map(|group| group.len().to_string())
+                .collect::<Vec<_>>();
+
+            /* Every unknown spring multiplies the arrangements, and unfolded records with too
+             * many of them would overflow, so only a third of the springs are hidden. */
+            for spring in &mut springs {
+                if rng.one_in(3) {
+                    *spring = '?';
+                }
+            }
+
+            format!(
+                "{} {}",
+                springs.iter().collect::<String>(),
+                groups.join(",")
+            )
+        }))
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, GridTile)]
+pub enum Spring {
+    #[tile('.')]
+    Operational,
+    #[tile('#')]
+    Damaged,
+    #[tile('?')]
+    Unknown,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Record {
+    pub springs: Vec<Spring>,
+    pub group_lengths: Vec<usize>,
+}
+
+impl FromStr for Record {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (springs, group_lengths) = parse::split_once(s, " ")?;
+
+        let springs = springs
+            .char_indices()
+            .map(|(i, ch)| {
+                Spring::try_from(ch)
+                    .map_err(|err| ParseError::new(&springs[i..i + ch.len_utf8()], err.expected))
+            })
+            .collect::<Result<Vec<Spring>, _>>()?;
+
+        let group_lengths = group_lengths
+            .split(',')
+            .map(parse::number)
+            .collect::<Result<Vec<usize>, _>>()?;
+
+        Ok(Self {
+            springs,
+            group_lengths,
+        })
+    }
+}
+
+impl Record {
+    pub fn unfold(self) -> Self {
+        let springs: Vec<Spring> = repeat_n(vec![self.springs, vec![Spring::Unknown]], 5)
+            .flatten()
+            .flatten()
+            .collect();
+
+        let group_lengths = repeat_n(self.group_lengths, 5).flatten().collect();
+
+        Self {
+            springs: springs[..springs.len() - 1].to_vec(),
+            group_lengths,
+        }
+    }
+
+    pub fn arrangements(&self) -> usize {
+        let mut memo = HashMap::new();
+        self.partial_arrangements(&mut memo, 0, 0)
+    }
+
+    fn partial_arrangements(
+        &self,
+        cache: &mut HashMap<(usize, usize), usize>,
+        spring_index: usize,
+        group_index: usize,
+    ) -> usize {
+        if let Some(result) = cache.get(&(spring_index, group_index)) {
+            return *result;
+        }
+
+        let consume_group = self.group_lengths.get(group_index).map_or(0, |length| {
+            /* Group lengths come straight from the input, and may be large enough to overflow */
+            let Some(end) = spring_index
+                .checked_add(*length)
+                .filter(|&end| end <= self.springs.len())
+            else {
+                return 0;
+            };
+
+            if self.springs[spring_index..end].contains(&Spring::Operational) {
+                return 0;
+            }
+
+            if let Some(Spring::Damaged) = self.springs.get(end) {
+                return 0;
+            }
+
+            self.partial_arrangements(cache, end + 1, group_index + 1)
+        });
+
+        let consume_element = match self.springs.get(spring_index) {
+            None => (group_index >= self.group_lengths.len()) as usize,
+            Some(Spring::Damaged) => 0,
+            Some(_) => self.partial_arrangements(cache, spring_index + 1, group_index),
+        };
+
+        let result = consume_group + consume_element;
+        cache.insert((spring_index, group_index), result);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Example;
+    use proptest::prelude::*;
+    use rstest::*;
+    use std::path::PathBuf;
+
+    #[rstest]
+    #[case("???.### 1,1,3", 1)]
+    #[case(".??..??...?##. 1,1,3", 4)]
+    #[case("?#?#?#?#?#?#?#? 1,3,1,6", 1)]
+    #[case("????.#...#... 4,1,1", 1)]
+    #[case("????.######..#####. 1,6,5", 4)]
+    #[case("?###???????? 3,2,1", 10)]
+    #[case("? 18446744073709551615", 0)]
+    fn test_record_arrangements(#[case] record: Record, #[case] expected: usize) {
+        assert_eq!(record.arrangements(), expected);
+    }
+
+    #[rstest]
+    #[case(".# 1", ".#?.#?.#?.#?.# 1,1,1,1,1")]
+    #[case(".??..??...?##. 1,1,3", ".??..??...?##.?.??..??...?##.?.??..??...?##.?.??..??...?##.?.??..??...?##. 1,1,3,1,1,3,1,1,3,1,1,3,1,1,3")]
+    #[case("?#?#?#?#?#?#?#? 1,3,1,6", "?#?#?#?#?#?#?#???#?#?#?#?#?#?#???#?#?#?#?#?#?#???#?#?#?#?#?#?#???#?#?#?#?#?#?#? 1,3,1,6,1,3,1,6,1,3,1,6,1,3,1,6,1,3,1,6")]
+    #[case("????.#...#... 4,1,1", "????.#...#...?????.#...#...?????.#...#...?????.#...#...?????.#...#... 4,1,1,4,1,1,4,1,1,4,1,1,4,1,1")]
+    #[case("????.######..#####. 1,6,5", "????.######..#####.?????.######..#####.?????.######..#####.?????.######..#####.?????.######..#####. 1,6,5,1,6,5,1,6,5,1,6,5,1,6,5")]
+    #[case("?###???????? 3,2,1", "?###??????????###??????????###??????????###??????????###???????? 3,2,1,3,2,1,3,2,1,3,2,1,3,2,1")]
+    fn test_record_unfold(#[case] record: Record, #[case] expected: Record) {
+        assert_eq!(record.unfold(), expected);
+    }
+
+    #[rstest]
+    fn test_examples(#[files("fixtures/*.in")] path: PathBuf) -> Result<()> {
+        Example::load(&path)?.check::<Day12>()
+    }
+
+    /// Tries every way of replacing the unknown springs, and counts those that match the groups
+    fn naive_arrangements(record: &Record) -> usize {
+        let unknown = record
+            .springs
+            .iter()
+            .filter(|&&spring| spring == Spring::Unknown)
+            .count();
+
+        (0..1u32 << unknown)
+            .filter(|assignment| {
+                let mut next_unknown = 0;
+                let damaged = record
+                    .springs
+                    .iter()
+                    .map(|&spring| match spring {
+                        Spring::Unknown => {
+                            next_unknown += 1;
+                            assignment & (1 << (next_unknown - 1)) != 0
+                        }
+                        spring => spring == Spring::Damaged,
+                    })
+                    .collect::<Vec<_>>();
+
+                let groups = damaged
+                    .split(|&damaged| !damaged)
+                    .map(<[bool]>::len)
+                    .filter(|&len| len > 0)
+                    .collect::<Vec<_>>();
+
+                groups == record.group_lengths
+            })
+            .count()
+    }
+
+    fn records() -> impl Strategy<Value = Record> {
+        let spring = prop_oneof![
+            Just(Spring::Operational),
+            Just(Spring::Damaged),
+            Just(Spring::Unknown),
+        ];
+        (
+            prop::collection::vec(spring, 1..14),
+            prop::collection::vec(1usize..5, 1..5),
+        )
+            .prop_map(|(springs, group_lengths)| Record {
+                springs,
+                group_lengths,
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn test_arrangements(record in records()) {
+            prop_assert_eq!(record.arrangements(), naive_arrangements(&record));
+        }
+    }
+}
@@ -0,0 +1,262 @@
+use anyhow::{anyhow, Result};
+use common::{parse, Params, Rng, Solution};
+use grid::{Grid, Point};
+use std::{iter::zip, ops::Not};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Reflection {
+    Row(usize),
+    Column(usize),
+}
+
+/// The rows that have as many identical rows above them as fit below (in reverse order)
+pub fn mirrored_rows<T>(pattern: &Grid<T>) -> impl Iterator<Item = usize> + '_
+where
+    T: Eq + PartialEq,
+{
+    (1..pattern.height()).filter(|&row| {
+        zip((0..row).rev(), row..pattern.height())
+            .all(|(above, below)| pattern.row(above) == pattern.row(below))
+    })
+}
+
+pub fn reflections<T>(pattern: &Grid<T>) -> Vec<Reflection>
+where
+    T: Copy + Eq + PartialEq,
+{
+    mirrored_rows(pattern)
+        .map(Reflection::Row)
+        .chain(mirrored_rows(&pattern.transpose()).map(Reflection::Column))
+        .collect()
+}
+
+/// The reflection that appears once exactly one tile of `pattern` is flipped
+pub fn unsmudged_reflection<T>(pattern: &mut Grid<T>) -> Option<Reflection>
+where
+    T: Copy + Eq + PartialEq + Not<Output = T> + std::fmt::Debug,
+{
+    let smudged_reflection = reflections(pattern).first().copied();
+
+    // yikes... 😬
+    for i in 0..pattern.height() {
+        for j in 0..pattern.width() {
+            let point = Point::new(i, j);
+            pattern[point] = !pattern[point];
+
+            if let Some(reflect) = reflections(pattern)
+                .iter()
+                .find(|&&r| Some(r) != smudged_reflection)
+            {
+                return Some(*reflect);
+            }
+
+            pattern[point] = !pattern[point];
+        }
+    }
+
+    None
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Point of Incidence";
+
+    /// Every pattern, with `#` as `true` (rocks) and `.` as `false` (ash)
+    type Input = Vec<Grid<bool>>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse::sections(input)
+            .into_iter()
+            .map(|pattern| Grid::parse(pattern, parse_tile))
+            .collect()
+    }
+
+    fn part_one(patterns: &Self::Input, _: &Params) -> Result<usize> {
+        patterns
+            .iter()
+            .map(|pattern| match reflections(pattern).first() {
+                Some(Reflection::Row(row)) => Ok(100 * row),
+                Some(Reflection::Column(col)) => Ok(*col),
+                None => Err(anyhow!("Pattern without a reflection:\n{}", show(pattern))),
+            })
+            .sum()
+    }
+
+    fn part_two(patterns: &Self::Input, _: &Params) -> Result<usize> {
+        patterns
+            .iter()
+            .map(|pattern| match unsmudged_reflection(&mut pattern.clone()) {
+                Some(Reflection::Row(row)) => Ok(100 * row),
+                Some(Reflection::Column(col)) => Ok(col),
+                None => Err(anyhow!(
+                    "Pattern without a smudged reflection:\n{}",
+                    show(pattern)
+                )),
+            })
+            .sum()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let patterns = (0..size.max(1))
+            .map(|_| {
+                let pattern = planted_pattern(rng);
+                if rng.one_in(2) {
+                    show(&pattern.transpose()).to_string()
+                } else {
+                    show(&pattern).to_string()
+                }
+            })
+            .collect::<Vec<_>>();
+        Some(patterns.join("\n\n") + "\n")
+    }
+}
+
+/// The line that `i` is mirrored to by a mirror before line `mirror`, if it's within `len` lines
+fn mirrored(i: usize, mirror: usize, len: usize) -> Option<usize> {
+    (2 * mirror - 1).checked_sub(i).filter(|&other| other < len)
+}
+
+/* A pattern that reflects across a row, and would also reflect across a column if it wasn't for a
+ * smudge. The smudge is in a row that the row's reflection doesn't reach, so that it doesn't break
+ * it, which needs a mirror that isn't right in the middle. */
+fn planted_pattern(rng: &mut Rng) -> Grid<bool> {
+    let (width, height) = (rng.range(5..18), rng.range(5..18));
+    let row = loop {
+        let row = rng.range(1..height);
+        if 2 * row != height {
+            break row;
+        }
+    };
+    let col = rng.range(1..width);
+
+    let rocks = Grid::filled(width, height, false).map(|_| rng.one_in(2));
+    let mut pattern = rocks.map(|_| false);
+    for point in pattern.points().collect::<Vec<_>>() {
+        let original = Point::new(
+            point
+                .row
+                .min(mirrored(point.row, row, height).unwrap_or(point.row)),
+            point
+                .col
+                .min(mirrored(point.col, col, width).unwrap_or(point.col)),
+        );
+        pattern[point] = rocks[original];
+    }
+
+    let smudges = pattern
+        .points()
+        .filter(|point| {
+            mirrored(point.row, row, height).is_none() && mirrored(point.col, col, width).is_some()
+        })
+        .collect::<Vec<_>>();
+    let smudge = *rng.pick(&smudges);
+    pattern[smudge] = !pattern[smudge];
+    pattern
+}
+
+fn parse_tile(ch: char) -> Result<bool> {
+    match ch {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        _ => Err(anyhow!("`#` or `.`")),
+    }
+}
+
+fn show(pattern: &Grid<bool>) -> Grid<char> {
+    pattern.map(|&rock| if rock { '#' } else { '.' })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Example;
+    use indoc::*;
+    use rstest::*;
+    use std::path::PathBuf;
+
+    #[rstest]
+    #[case(
+        indoc! {"
+        #...##..#
+        #....#..#
+        ..##..###
+        #####.##.
+        #####.##.
+        ..##..###
+        #....#..#
+    "}, vec![Reflection::Row(4)])]
+    #[case(
+        indoc! {"
+        #.##..##.
+        ..#.##.#.
+        ##......#
+        ##......#
+        ..#.##.#.
+        ..##..##.
+        #.#.##.#.
+    "}, vec![Reflection::Column(5)])]
+    #[case(
+        indoc! {"
+        #...#..##
+        #.#.#..##
+        ###..##..
+        #..##.###
+        ..#.#.#..
+        #####..##
+        .#....#..
+    "}, vec![Reflection::Column(8)])]
+    fn test_reflection(#[case] pattern: &str, #[case] expected: Vec<Reflection>) {
+        assert_eq!(reflections(&Grid::parse(pattern, Ok).unwrap()), expected);
+    }
+
+    #[rstest]
+    #[case(
+        indoc! {"
+        #.##..##.
+        ..#.##.#.
+        ##......#
+        ##......#
+        ..#.##.#.
+        ..##..##.
+        #.#.##.#.
+    "}, Some(Reflection::Row(3)))]
+    #[case(
+        indoc! {"
+        #...##..#
+        #....#..#
+        ..##..###
+        #####.##.
+        #####.##.
+        ..##..###
+        #....#..#
+    "}, Some(Reflection::Row(1)))]
+    #[case(
+        indoc! {"
+        ###.###.#.#
+        ...##......
+        ..#########
+        ###.#......
+        ##....#####
+        ......#..##
+        ###....#...
+        ...##....##
+        ##...#..###
+    "}, Some(Reflection::Column(10)))]
+    /* Without a reflection to begin with, any one that appears is new */
+    #[case("#.#\n", Some(Reflection::Column(1)))]
+    fn test_unsmudged_reflection(#[case] pattern: &str, #[case] expected: Option<Reflection>) {
+        assert_eq!(
+            unsmudged_reflection(&mut Grid::parse(pattern, parse_tile).unwrap()),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn test_examples(#[files("fixtures/*.in")] path: PathBuf) -> Result<()> {
+        Example::load(&path)?.check::<Day13>()
+    }
+}
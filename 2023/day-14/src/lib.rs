@@ -0,0 +1,354 @@
+use anyhow::Result;
+use common::{Param, Params, Rng, Solution};
+use grid::{Direction, Grid, GridTile};
+use itertools::Itertools;
+use std::{
+    fmt::{Debug, Display},
+    str::FromStr,
+};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Parabolic Reflector Dish";
+    const PARAMS: &'static [Param] = &[Param {
+        name: "cycles",
+        default: "1000000000",
+        description: "Number of spin cycles in part two",
+    }];
+
+    type Input = Platform;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part_one(platform: &Self::Input, _: &Params) -> Result<usize> {
+        let mut platform = platform.clone();
+        platform.move_rocks(Direction::North);
+        Ok(platform.load())
+    }
+
+    fn part_two(platform: &Self::Input, params: &Params) -> Result<usize> {
+        let mut platform = platform.clone();
+        platform.cycle(params.get("cycles")?);
+        Ok(platform.load())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(rng.grid(size, size, |rng| match rng.range(0..10) {
+            0 | 1 => 'O',
+            2 => '#',
+            _ => '.',
+        }))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, GridTile)]
+pub enum Tile {
+    #[tile('O')]
+    RoundedRock,
+    #[tile('#')]
+    CubeRock,
+    #[tile('.')]
+    Space,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Platform {
+    platform: Grid<Tile>,
+}
+
+impl FromStr for Platform {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            platform: s.parse()?,
+        })
+    }
+}
+
+impl Display for Platform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.platform)
+    }
+}
+
+/* The tiles read better than the cells of the grid when a comparison fails, from the start of
+ * a line so that the rows line up */
+impl Debug for Platform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f)?;
+        Display::fmt(self, f)
+    }
+}
+
+impl Platform {
+    pub fn move_rocks(&mut self, direction: Direction) {
+        /* Rocks closest to the edge they're rolling towards have to settle first, so that the
+         * ones behind them stop right before them. */
+        let mut points = self.platform.points().collect_vec();
+        if matches!(direction, Direction::South | Direction::East) {
+            points.reverse();
+        }
+
+        for point in points {
+            if self.platform[point] == Tile::RoundedRock {
+                let mut destination = point;
+                while let Some(next) = self
+                    .platform
+                    .step(destination, direction)
+                    .filter(|&next| self.platform[next] == Tile::Space)
+                {
+                    destination = next;
+                }
+
+                self.platform[point] = Tile::Space;
+                self.platform[destination] = Tile::RoundedRock;
+            }
+        }
+    }
+
+    pub fn cycle(&mut self, times: usize) {
+        let mut seen = vec![self.clone()];
+        for _ in 0..times {
+            self.move_rocks(Direction::North);
+            self.move_rocks(Direction::West);
+            self.move_rocks(Direction::South);
+            self.move_rocks(Direction::East);
+
+            if let Some(index) = seen.iter().position(|e| e == self) {
+                let period = seen.len() - index;
+                let final_index = index + (times - index) % period;
+                *self = seen[final_index].clone();
+                break;
+            }
+
+            seen.push(self.clone());
+        }
+    }
+
+    pub fn load(&self) -> usize {
+        self.platform
+            .rows()
+            .rev()
+            .enumerate()
+            .map(|(i, row)| {
+                (i + 1)
+                    * row
+                        .iter()
+                        .filter(|&&tile| tile == Tile::RoundedRock)
+                        .count()
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Example;
+    use indoc::*;
+    use rstest::*;
+    use std::path::PathBuf;
+
+    #[rstest]
+    #[case(
+    indoc! {"
+        O....#....
+        O.OO#....#
+        .....##...
+        OO.#O....O
+        .O.....O#.
+        O.#..O.#.#
+        ..O..#O..O
+        .......O..
+        #....###..
+        #OO..#....
+    "}, 
+    Direction::North,
+    indoc! {"
+        OOOO.#.O..
+        OO..#....#
+        OO..O##..O
+        O..#.OO...
+        ........#.
+        ..#....#.#
+        ..O..#.O.O
+        ..O.......
+        #....###..
+        #....#....
+    "})]
+    #[case(
+    indoc! {"
+        #OO..#....
+        #....###..
+        .......O..
+        ..O..#O..O
+        O.#..O.#.#
+        .O.....O#.
+        OO.#O....O
+        .....##...
+        O.OO#....#
+        O....#....
+    "}, 
+    Direction::South,
+    indoc! {"
+        #....#....
+        #....###..
+        ..O.......
+        ..O..#.O.O
+        ..#....#.#
+        ........#.
+        O..#.OO...
+        OO..O##..O
+        OO..#....#
+        OOOO.#.O..
+    "})]
+    #[case(
+    indoc! {"
+        OO#...O
+        ..O.O#O
+        O.O.O.#
+    "}, 
+    Direction::West,
+    indoc! {"
+        OO#O...
+        OO...#O
+        OOO...#
+    "})]
+    #[case(
+    indoc! {"
+        #OO.#.O..O
+        OOOOO.....
+        O#O#O#O#O.
+    "}, 
+    Direction::East,
+    indoc! {"
+        #.OO#...OO
+        .....OOOOO
+        O#O#O#O#.O
+    "})]
+    fn test_move_rocks(
+        #[case] platform: &str,
+        #[case] direction: Direction,
+        #[case] expected: &str,
+    ) {
+        let mut platform: Platform = platform.parse().unwrap();
+        platform.move_rocks(direction);
+        assert_eq!(platform, expected.parse().unwrap());
+    }
+
+    #[rstest]
+    #[case(
+    indoc! {"
+        OOOO.#.O..
+        OO..#....#
+        OO..O##..O
+        O..#.OO...
+        ........#.
+        ..#....#.#
+        ..O..#.O.O
+        ..O.......
+        #....###..
+        #....#....
+    "},
+    1,
+    indoc! {"
+        .....#....
+        ....#...O#
+        ...OO##...
+        .OO#......
+        .....OOO#.
+        .O#...O#.#
+        ....O#....
+        ......OOOO
+        #...O###..
+        #..OO#....
+    "})]
+    #[case(
+    indoc! {"
+        OOOO.#.O..
+        OO..#....#
+        OO..O##..O
+        O..#.OO...
+        ........#.
+        ..#....#.#
+        ..O..#.O.O
+        ..O.......
+        #....###..
+        #....#....
+    "},
+    2,
+    indoc! {"
+        .....#....
+        ....#...O#
+        .....##...
+        ..O#......
+        .....OOO#.
+        .O#...O#.#
+        ....O#...O
+        .......OOO
+        #..OO###..
+        #.OOO#...O
+    "})]
+    #[case(
+    indoc! {"
+        OOOO.#.O..
+        OO..#....#
+        OO..O##..O
+        O..#.OO...
+        ........#.
+        ..#....#.#
+        ..O..#.O.O
+        ..O.......
+        #....###..
+        #....#....
+    "},
+    3,
+    indoc! {"
+        .....#....
+        ....#...O#
+        .....##...
+        ..O#......
+        .....OOO#.
+        .O#...O#.#
+        ....O#...O
+        .......OOO
+        #...O###.O
+        #.OOO#...O
+    "})]
+    fn test_cycle(#[case] platform: &str, #[case] times: usize, #[case] expected: &str) {
+        let mut platform: Platform = platform.parse().unwrap();
+        platform.cycle(times);
+        assert_eq!(platform, expected.parse().unwrap());
+    }
+
+    #[rstest]
+    #[case(
+        indoc! {"
+        OOOO.#.O..
+        OO..#....#
+        OO..O##..O
+        O..#.OO...
+        ........#.
+        ..#....#.#
+        ..O..#.O.O
+        ..O.......
+        #....###..
+        #....#....
+    "}, 136)]
+    fn test_calculate_load(#[case] platform: &str, #[case] expected: usize) {
+        let platform: Platform = platform.parse().unwrap();
+        assert_eq!(platform.load(), expected);
+    }
+
+    #[rstest]
+    fn test_examples(#[files("fixtures/*.in")] path: PathBuf) -> Result<()> {
+        Example::load(&path)?.check::<Day14>()
+    }
+}
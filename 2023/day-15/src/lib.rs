@@ -0,0 +1,163 @@
+use anyhow::Result;
+use common::{parse, Params, ParseError, Rng, Solution};
+use std::str::FromStr;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Lens Library";
+
+    /// The steps of the initialization sequence
+    type Input = Vec<String>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .trim()
+            .split(',')
+            .map(|step| {
+                let step = step.trim();
+                step.parse::<Operation>()?;
+                Ok(step.to_owned())
+            })
+            .collect()
+    }
+
+    fn part_one(steps: &Self::Input, _: &Params) -> Result<usize> {
+        Ok(steps.iter().map(|step| hash(step)).sum())
+    }
+
+    fn part_two(steps: &Self::Input, _: &Params) -> Result<usize> {
+        let operations = steps
+            .iter()
+            .map(|step| step.parse())
+            .collect::<Result<Vec<Operation>>>()?;
+
+        Ok(focusing_power(operations))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        /* Few enough labels that lenses get replaced and removed */
+        let labels = (0..size / 4 + 1)
+            .map(|_| {
+                let len = rng.range(2..7);
+                rng.letters(len)
+            })
+            .collect::<Vec<_>>();
+
+        let steps = (0..size)
+            .map(|_| {
+                let label = rng.pick(&labels);
+                if rng.one_in(3) {
+                    format!("{label}-")
+                } else {
+                    format!("{label}={}", rng.range(1..10))
+                }
+            })
+            .collect::<Vec<_>>();
+        Some(steps.join(",") + "\n")
+    }
+}
+
+pub fn focusing_power(operations: Vec<Operation>) -> usize {
+    let mut boxes: [Vec<Lens>; 256] = std::array::from_fn(|_| Vec::new());
+
+    operations.into_iter().for_each(|op| match op {
+        Operation::RemoveLens { box_number, lens } => {
+            if let Some(index) = boxes[box_number].iter().position(|l| l.label == lens.label) {
+                boxes[box_number].remove(index);
+            }
+        }
+        Operation::AddLens { box_number, lens } => {
+            if let Some(index) = boxes[box_number].iter().position(|l| l.label == lens.label) {
+                boxes[box_number][index] = lens;
+            } else {
+                boxes[box_number].push(lens);
+            }
+        }
+    });
+
+    boxes
+        .iter()
+        .enumerate()
+        .map(|(box_number, b)| {
+            b.iter()
+                .enumerate()
+                .map(|(lens_number, l)| (box_number + 1) * (lens_number + 1) * l.focal_length)
+                .sum::<usize>()
+        })
+        .sum()
+}
+
+pub struct Lens {
+    pub label: String,
+    pub focal_length: usize,
+}
+
+pub enum Operation {
+    RemoveLens { box_number: usize, lens: Lens },
+    AddLens { box_number: usize, lens: Lens },
+}
+
+impl FromStr for Operation {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((label, _)) = s.split_once("-") {
+            Ok(Operation::RemoveLens {
+                box_number: hash(label),
+                lens: Lens {
+                    label: label.to_string(),
+                    focal_length: 0,
+                },
+            })
+        } else if let Some((label, focal_length)) = s.split_once("=") {
+            Ok(Operation::AddLens {
+                box_number: hash(label),
+                lens: Lens {
+                    label: label.to_string(),
+                    focal_length: parse::number(focal_length)?,
+                },
+            })
+        } else {
+            Err(ParseError::new(s, "`LABEL-` or `LABEL=FOCAL_LENGTH`").into())
+        }
+    }
+}
+
+pub fn hash(input: &str) -> usize {
+    input
+        .chars()
+        .fold(0, |acc, ch| ((acc + ch as usize) * 17) % 256)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Example;
+    use rstest::*;
+    use std::path::PathBuf;
+
+    #[rstest]
+    #[case("rn=1", 30)]
+    #[case("cm-", 253)]
+    #[case("qp=3", 97)]
+    #[case("cm=2", 47)]
+    #[case("qp-", 14)]
+    #[case("pc=4", 180)]
+    #[case("ot=9", 9)]
+    #[case("ab=5", 197)]
+    #[case("pc-", 48)]
+    #[case("pc=6", 214)]
+    #[case("ot=7", 231)]
+    fn test_hash(#[case] input: &str, #[case] expected: usize) {
+        assert_eq!(hash(input), expected);
+    }
+
+    #[rstest]
+    fn test_examples(#[files("fixtures/*.in")] path: PathBuf) -> Result<()> {
+        Example::load(&path)?.check::<Day15>()
+    }
+}
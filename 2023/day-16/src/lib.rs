@@ -0,0 +1,182 @@
+use anyhow::{bail, Result};
+use common::{Params, Rng, Solution};
+use grid::{Direction, Grid, GridTile, Point};
+use petgraph::graph::UnGraph;
+use std::collections::HashSet;
+
+/* This solution is an abomination, and is probably way slower than it should be. But at least now
+* I have an inkling of how to use petgraph...
+*
+* Basically what I do is:
+*   Parse the input into an undirected graph representing all of the beams. The nodes contain the
+*   coordinates (row, column) in the grid where the beam passes, and the edges coontain the
+*   direction the beam takes to get to the next node.
+*   Let's say we start on the edge of the grid at coordinates (r, c) with direction d. We keep
+*   track of a list of `leaf` nodes, that is, the nodes from which we continue to expand the graph,
+*   as well as a HashSet that contains the tiles we visited, and the direction we came from.
+*   Nodes in the visisted set are never added to the leaves list.
+*
+* This runs in ~20s for Part 2, which is a bit embarassing. Maybe I'll find a way to optimize this
+* (or maybe a better solution altogether).
+*/
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, GridTile)]
+pub enum Tile {
+    #[tile('.')]
+    Space,
+    #[tile('\\')]
+    LeftMirror,
+    #[tile('/')]
+    RightMirror,
+    #[tile('|')]
+    VerticalSplitter,
+    #[tile('-')]
+    HorizontalSplitter,
+}
+
+pub fn beam_graph(grid: &Grid<Tile>, start: (Point, Direction)) -> UnGraph<Point, Direction> {
+    let mut beam = UnGraph::default();
+
+    let (position, dir) = start;
+    let root = beam.add_node(position);
+    let edge = beam.add_edge(root, root, dir);
+
+    let mut beam_heads = vec![(edge, root)];
+    let mut visited: HashSet<(Point, Direction)> = HashSet::new();
+
+    loop {
+        let mut new_heads = Vec::new();
+        let mut visited_new_node = false;
+        for &(came_from, head) in &beam_heads {
+            let position = beam[head];
+            let current_direction = beam[came_from];
+
+            visited_new_node = visited_new_node || visited.insert((position, current_direction));
+
+            let new_directions = match grid[position] {
+                Tile::Space => vec![current_direction],
+                Tile::LeftMirror => vec![current_direction.reflect_backslash()],
+                Tile::RightMirror => vec![current_direction.reflect_slash()],
+                Tile::VerticalSplitter if !current_direction.is_vertical() => {
+                    vec![Direction::North, Direction::South]
+                }
+                Tile::HorizontalSplitter if current_direction.is_vertical() => {
+                    vec![Direction::East, Direction::West]
+                }
+                Tile::VerticalSplitter | Tile::HorizontalSplitter => vec![current_direction],
+            };
+
+            for new_direction in new_directions {
+                if let Some(next_position) = grid.step(position, new_direction) {
+                    if let Some(new_head) = beam
+                        .node_indices()
+                        .find(|&index| beam[index] == next_position)
+                    {
+                        if !beam
+                            .edges_connecting(head, new_head)
+                            .any(|edge| edge.weight() == &new_direction)
+                        {
+                            let new_edge = beam.add_edge(head, new_head, new_direction);
+                            new_heads.push((new_edge, new_head));
+                        }
+                    } else {
+                        let new_node = beam.add_node(next_position);
+                        let new_edge = beam.add_edge(head, new_node, new_direction);
+
+                        new_heads.push((new_edge, new_node));
+                    }
+                }
+            }
+        }
+
+        beam_heads = new_heads;
+
+        if !visited_new_node {
+            break;
+        }
+    }
+
+    beam
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const TITLE: &'static str = "The Floor Will Be Lava";
+
+    type Input = Grid<Tile>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part_one(grid: &Self::Input, _: &Params) -> Result<usize> {
+        let start = Point::new(0, 0);
+        if !grid.contains(start) {
+            bail!("The contraption is empty");
+        }
+        Ok(beam_graph(grid, (start, Direction::East)).node_count())
+    }
+
+    fn part_two(grid: &Self::Input, _: &Params) -> Result<usize> {
+        let (width, height) = (grid.width(), grid.height());
+        if width == 0 || height == 0 {
+            bail!("The contraption is empty");
+        }
+        let vertical = (0..height).map(|i| {
+            let left = beam_graph(grid, (Point::new(i, 0), Direction::East)).node_count();
+            let right = beam_graph(grid, (Point::new(i, width - 1), Direction::West)).node_count();
+
+            left.max(right)
+        });
+
+        let horizontal = (0..width).map(|j| {
+            let up = beam_graph(grid, (Point::new(0, j), Direction::South)).node_count();
+            let down = beam_graph(grid, (Point::new(height - 1, j), Direction::North)).node_count();
+
+            up.max(down)
+        });
+
+        Ok(vertical
+            .chain(horizontal)
+            .max()
+            .expect("The contraption isn't empty"))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(rng.grid(size, size, |rng| {
+            if rng.one_in(8) {
+                *rng.pick(&['/', '\\', '|', '-'])
+            } else {
+                '.'
+            }
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Example;
+    use rstest::*;
+    use std::path::PathBuf;
+
+    #[rstest]
+    fn test_examples(#[files("fixtures/*.in")] path: PathBuf) -> Result<()> {
+        Example::load(&path)?.check::<Day16>()
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("\n")]
+    fn test_empty_contraption(#[case] input: &str) -> Result<()> {
+        let params = Params::defaults(Day16::PARAMS);
+        let grid = Day16::parse(input)?;
+        assert!(Day16::part_one(&grid, &params).is_err());
+        assert!(Day16::part_two(&grid, &params).is_err());
+        Ok(())
+    }
+}
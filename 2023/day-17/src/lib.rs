@@ -0,0 +1,180 @@
+use anyhow::{anyhow, bail, Result};
+use common::{Params, Rng, Solution};
+use grid::{Direction, Grid, Point};
+use itertools::Itertools;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
+/* With heavy inspiration from https://gist.github.com/icub3d/ff31909ccb22fa16e3717cf72a59028e
+* Couldn't for the life of me figure out why my first attempts were not working. At least now I
+* know how to implement Dijkstra's algorithm... */
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Node {
+    position: Point,
+    direction: Direction,
+    len: usize,
+}
+
+impl Node {
+    pub fn new(position: Point, direction: Direction, len: usize) -> Self {
+        Self {
+            position,
+            direction,
+            len,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Step {
+    node: Node,
+    cost: usize,
+}
+
+impl Ord for Step {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.cmp(&self.cost)
+    }
+}
+
+impl PartialOrd for Step {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn neighbors<const MIN_STEP: usize, const MAX_STEP: usize>(
+    node: &Node,
+    grid: &Grid<usize>,
+) -> Vec<Node> {
+    Direction::ALL
+        .into_iter()
+        .filter_map(|direction| {
+            if direction != node.direction.reverse() {
+                let new_position = grid.step(node.position, direction);
+                if direction != node.direction && node.len >= MIN_STEP {
+                    Some(Node::new(new_position?, direction, 1))
+                } else if direction == node.direction && node.len < MAX_STEP {
+                    Some(Node::new(new_position?, direction, node.len + 1))
+                } else {
+                    None
+                }
+            } else {
+                None
+            }
+        })
+        .collect_vec()
+}
+
+/// The least heat lost on the way from `start` to `target`, moving between `MIN_STEP` and
+/// `MAX_STEP` blocks in a straight line
+pub fn dijkstra<const MIN_STEP: usize, const MAX_STEP: usize>(
+    costs: &Grid<usize>,
+    start: Point,
+    target: Point,
+) -> Option<usize> {
+    let mut distances = HashMap::new();
+    distances.insert(Node::new(start, Direction::South, 0), 0);
+    distances.insert(Node::new(start, Direction::East, 0), 0);
+
+    let mut frontier = BinaryHeap::new();
+    frontier.push(Step {
+        cost: 0,
+        node: Node::new(start, Direction::South, 0),
+    });
+    frontier.push(Step {
+        cost: 0,
+        node: Node::new(start, Direction::East, 0),
+    });
+
+    while let Some(current_step) = frontier.pop() {
+        if current_step.node.position == target && current_step.node.len >= MIN_STEP {
+            return Some(current_step.cost);
+        }
+
+        for neighbor in neighbors::<MIN_STEP, MAX_STEP>(&current_step.node, costs) {
+            let tentative_cost = current_step.cost + costs[neighbor.position];
+
+            if let Some(&best_cost) = distances.get(&neighbor) {
+                if tentative_cost >= best_cost {
+                    continue;
+                }
+            }
+
+            distances.insert(neighbor, tentative_cost);
+            frontier.push(Step {
+                node: neighbor,
+                cost: tentative_cost,
+            });
+        }
+    }
+
+    None
+}
+
+/// The bottom-right city block, where the crucible is headed
+fn factory(grid: &Grid<usize>) -> Result<Point> {
+    match (grid.height().checked_sub(1), grid.width().checked_sub(1)) {
+        (Some(row), Some(col)) => Ok(Point::new(row, col)),
+        _ => bail!("The city is empty"),
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const TITLE: &'static str = "Clumsy Crucible";
+
+    /// The heat loss of every city block
+    type Input = Grid<usize>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::parse(input, |ch| {
+            ch.to_digit(10)
+                .map(|digit| digit as usize)
+                .ok_or(anyhow!("a digit"))
+        })
+    }
+
+    fn part_one(grid: &Self::Input, _: &Params) -> Result<usize> {
+        dijkstra::<1, 3>(grid, Point::new(0, 0), factory(grid)?)
+            .ok_or(anyhow!("The crucible can't reach the factory"))
+    }
+
+    fn part_two(grid: &Self::Input, _: &Params) -> Result<usize> {
+        dijkstra::<4, 10>(grid, Point::new(0, 0), factory(grid)?)
+            .ok_or(anyhow!("The crucible can't reach the factory"))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(rng.grid(size, size, |rng| char::from(b'0' + rng.range(1..10) as u8)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Example;
+    use rstest::*;
+    use std::path::PathBuf;
+
+    #[rstest]
+    fn test_examples(#[files("fixtures/*.in")] path: PathBuf) -> Result<()> {
+        Example::load(&path)?.check::<Day17>()
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("\n")]
+    fn test_empty_city(#[case] input: &str) -> Result<()> {
+        let params = Params::defaults(Day17::PARAMS);
+        let grid = Day17::parse(input)?;
+        assert!(Day17::part_one(&grid, &params).is_err());
+        assert!(Day17::part_two(&grid, &params).is_err());
+        Ok(())
+    }
+}
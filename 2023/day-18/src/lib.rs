@@ -0,0 +1,238 @@
+use anyhow::Result;
+use common::{parse, Params, ParseError, Rng, Solution};
+use glam::I64Vec2;
+use std::iter::zip;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    const TITLE: &'static str = "Lavaduct Lagoon";
+
+    /// Every line of the dig plan, read both as written and decoded from the hexadecimal color
+    type Input = Vec<(Instruction, Instruction)>;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.lines().map(parse_line).collect()
+    }
+
+    fn part_one(plan: &Self::Input, _: &Params) -> Result<i64> {
+        Ok(lagoon_size(plan.iter().map(|(instruction, _)| instruction)))
+    }
+
+    fn part_two(plan: &Self::Input, _: &Params) -> Result<i64> {
+        Ok(lagoon_size(plan.iter().map(|(_, instruction)| instruction)))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        /* Both plans go around a histogram, so that neither crosses itself, and they have as many
+         * instructions as long as they have as many columns. The hidden steps have 5 hex digits,
+         * which bounds the width of the whole histogram. */
+        let columns = (size / 2).clamp(1, 0xfffff / 2);
+        let plain = histogram(rng, columns, 10);
+        let hidden = histogram(rng, columns, 0xfffff / columns);
+
+        let lines = zip(plain, hidden).map(|((direction, steps), (hidden, hidden_steps))| {
+            let hidden = "RDLU"
+                .find(hidden)
+                .expect("Histograms only go in the four directions");
+            format!("{direction} {steps} (#{hidden_steps:05x}{hidden})\n")
+        });
+        Some(lines.collect())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub direction: I64Vec2,
+    pub steps: i64,
+}
+
+/// The instruction as written, and the one hidden in its colour code
+pub fn parse_line(line: &str) -> Result<(Instruction, Instruction)> {
+    let (direction, rest) = parse::split_once(line.trim(), " ")?;
+    let (steps, color) = parse::split_once(rest, " ")?;
+
+    let plain = Instruction {
+        direction: match direction {
+            "R" => Ok(I64Vec2::new(1, 0)),
+            "L" => Ok(I64Vec2::new(-1, 0)),
+            "U" => Ok(I64Vec2::new(0, 1)),
+            "D" => Ok(I64Vec2::new(0, -1)),
+            _ => Err(ParseError::new(direction, "`R`, `L`, `U` or `D`")),
+        }?,
+        steps: parse::number(steps)?,
+    };
+
+    let hex = color
+        .strip_prefix("(#")
+        .and_then(|color| color.strip_suffix(')'))
+        .ok_or(ParseError::new(color, "a color like `(#70c710)`"))?;
+    let color = i64::from_str_radix(hex, 16).map_err(|_| ParseError::new(hex, "a hex number"))?;
+
+    let decoded = Instruction {
+        direction: match color % 16 {
+            0 => Ok(I64Vec2::new(1, 0)),
+            1 => Ok(I64Vec2::new(0, -1)),
+            2 => Ok(I64Vec2::new(-1, 0)),
+            3 => Ok(I64Vec2::new(0, 1)),
+            _ => Err(ParseError::new(
+                &hex[hex.len() - 1..],
+                "a direction from 0 to 3",
+            )),
+        }?,
+        steps: color / 16,
+    };
+
+    Ok((plain, decoded))
+}
+
+/* Shoelace formula for the interior plus half of the perimeter, since the trench itself is one
+ * meter wide. */
+pub fn lagoon_size<'a>(instructions: impl Iterator<Item = &'a Instruction>) -> i64 {
+    let mut current_vertex = I64Vec2::ZERO;
+    let (perimeter, area) = instructions
+        .map(|instruction| {
+            let next_vertex = current_vertex + instruction.steps * instruction.direction;
+
+            let retval = (
+                instruction.steps,
+                (current_vertex.y + next_vertex.y) * (current_vertex.x - next_vertex.x),
+            );
+
+            current_vertex = next_vertex;
+
+            retval
+        })
+        .fold((0, 0), |(step, trapezoid), (perimeter, area)| {
+            (perimeter + step, area + trapezoid)
+        });
+
+    (area.abs() + perimeter) / 2 + 1
+}
+
+/// The outline of columns of random widths and heights (up to `longest`) standing side by side,
+/// as directions and steps. Neighbouring columns have different heights so that no step is empty.
+fn histogram(rng: &mut Rng, columns: usize, longest: usize) -> Vec<(char, usize)> {
+    let mut outline = Vec::new();
+    let (mut width, mut height) = (0, 0);
+    for _ in 0..columns {
+        let next = loop {
+            let next = rng.range(1..longest + 1);
+            if next != height {
+                break next;
+            }
+        };
+        outline.push(if next > height {
+            ('U', next - height)
+        } else {
+            ('D', height - next)
+        });
+        height = next;
+
+        let column = rng.range(1..longest + 1);
+        outline.push(('R', column));
+        width += column;
+    }
+    outline.push(('D', height));
+    outline.push(('L', width));
+    outline
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Example;
+    use proptest::prelude::*;
+    use rstest::*;
+    use std::{collections::HashSet, path::PathBuf};
+
+    #[rstest]
+    fn test_examples(#[files("fixtures/*.in")] path: PathBuf) -> Result<()> {
+        Example::load(&path)?.check::<Day18>()
+    }
+
+    /* The corners of a histogram shaped trench: along the bottom from the left, up the last
+     * column, then back left along the top of each column and down the first one. Column `col`
+     * is `widths[col]` meters wide and its top is `tops[col]` meters above the bottom. */
+    fn histogram_corners(widths: &[i64], tops: &[i64]) -> Vec<I64Vec2> {
+        let right = widths.iter().sum();
+        let mut corners = vec![I64Vec2::new(0, 0), I64Vec2::new(right, 0)];
+
+        let mut x = right;
+        for (width, &top) in widths.iter().zip(tops).rev() {
+            corners.push(I64Vec2::new(x, top));
+            x -= width;
+            corners.push(I64Vec2::new(x, top));
+        }
+
+        corners
+    }
+
+    /// Digs the trench one cube at a time, then counts every cube that can't be reached from
+    /// outside of it
+    fn naive_lagoon_size(instructions: &[Instruction]) -> i64 {
+        let mut trench = HashSet::from([I64Vec2::ZERO]);
+        let mut position = I64Vec2::ZERO;
+        for instruction in instructions {
+            for _ in 0..instruction.steps {
+                position += instruction.direction;
+                trench.insert(position);
+            }
+        }
+
+        let min = trench.iter().copied().reduce(I64Vec2::min).unwrap() - 1;
+        let max = trench.iter().copied().reduce(I64Vec2::max).unwrap() + 1;
+
+        let mut outside = HashSet::from([min]);
+        let mut stack = vec![min];
+        while let Some(cube) = stack.pop() {
+            for direction in [I64Vec2::X, I64Vec2::NEG_X, I64Vec2::Y, I64Vec2::NEG_Y] {
+                let next = cube + direction;
+                if next.cmpge(min).all()
+                    && next.cmple(max).all()
+                    && !trench.contains(&next)
+                    && outside.insert(next)
+                {
+                    stack.push(next);
+                }
+            }
+        }
+
+        let area = (max - min + 1).x * (max - min + 1).y;
+        area - outside.len() as i64
+    }
+
+    fn histograms() -> impl Strategy<Value = (Vec<i64>, Vec<i64>)> {
+        (1usize..8).prop_flat_map(|columns| {
+            (
+                prop::collection::vec(1i64..5, columns),
+                prop::collection::vec(1i64..12, columns),
+            )
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_shoelace((widths, tops) in histograms(), reversed: bool) {
+            let mut corners = histogram_corners(&widths, &tops);
+            if reversed {
+                corners.reverse();
+            }
+
+            let instructions = corners
+                .iter()
+                .zip(corners.iter().cycle().skip(1))
+                .map(|(&from, &to)| Instruction {
+                    direction: (to - from).signum(),
+                    steps: (to - from).abs().max_element(),
+                })
+                .filter(|instruction| instruction.steps > 0)
+                .collect::<Vec<_>>();
+
+            prop_assert_eq!(lagoon_size(instructions.iter()), naive_lagoon_size(&instructions));
+        }
+    }
+}
@@ -0,0 +1,392 @@
+use anyhow::{anyhow, bail, Result};
+use common::{parse, Params, ParseError, Rng, Solution};
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+
+mod workflow;
+
+pub use workflow::{Rule, Workflow, WorkflowResult};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    const TITLE: &'static str = "Aplenty";
+
+    type Input = System;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let [workflows, parts] = parse::sections(input)[..] else {
+            bail!(ParseError::new(
+                input,
+                "workflows and parts separated by a blank line"
+            ));
+        };
+
+        let lines = workflows.lines().map(str::trim).collect::<Vec<_>>();
+        let workflows = lines
+            .iter()
+            .map(|line| {
+                let workflow = line.parse::<Workflow>()?;
+                Ok((workflow.label.clone(), workflow))
+            })
+            .collect::<Result<HashMap<_, _>>>()?;
+
+        if let Some(label) = looping_workflow(&workflows) {
+            let line = lines
+                .iter()
+                .find(|line| line.starts_with(&format!("{label}{{")))
+                .expect("Every workflow has a line");
+            bail!(ParseError::new(
+                line,
+                "a workflow that parts can't get back to"
+            ));
+        }
+
+        let parts = parts
+            .lines()
+            .map(|line| parse_part(line.trim()))
+            .collect::<Result<_, _>>()?;
+
+        Ok(System { workflows, parts })
+    }
+
+    fn part_one(system: &Self::Input, _: &Params) -> Result<usize> {
+        let mut total = 0;
+        for part in &system.parts {
+            let mut current_workflow = system.workflow("in")?;
+
+            while let WorkflowResult::Jump(next) = current_workflow.check(part) {
+                current_workflow = system.workflow(&next)?;
+            }
+
+            if current_workflow.check(part) == WorkflowResult::Accepted {
+                total += part.iter().sum::<usize>();
+            }
+        }
+
+        Ok(total)
+    }
+
+    fn part_two(system: &Self::Input, _: &Params) -> Result<usize> {
+        Ok(count_matches(
+            &system.workflows,
+            "in".to_owned(),
+            0,
+            [1..4001, 1..4001, 1..4001, 1..4001],
+        ))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        fn label(workflow: usize) -> String {
+            if workflow == 0 {
+                return "in".to_owned();
+            }
+            /* At least three letters, so that `in` doesn't come up again */
+            let mut label = String::new();
+            let mut rest = workflow;
+            while rest > 0 || label.len() < 3 {
+                label.push(char::from(b'a' + (rest % 26) as u8));
+                rest /= 26;
+            }
+            label
+        }
+
+        /* The workflows form a tree, each one being sent parts by a single earlier one, so that
+         * parts can't loop and there aren't exponentially many paths through them. */
+        let mut unused = 1;
+        let workflows = rng.lines(size, |rng, workflow| {
+            unused = unused.max(workflow + 1);
+            let mut result = |rng: &mut Rng| {
+                if unused < size && rng.one_in(2) {
+                    unused += 1;
+                    label(unused - 1)
+                } else {
+                    rng.pick(&["A", "R"]).to_string()
+                }
+            };
+
+            let mut rules = (0..rng.range(1..4))
+                .map(|_| {
+                    let attr = rng.pick(&['x', 'm', 'a', 's']);
+                    let comparison = rng.pick(&['<', '>']);
+                    let value = rng.range(1..4001);
+                    format!("{attr}{comparison}{value}:{}", result(rng))
+                })
+                .collect::<Vec<_>>();
+            rules.push(result(rng));
+
+            format!("{}{{{}}}", label(workflow), rules.join(","))
+        });
+
+        let parts = rng.lines(size, |rng, _| {
+            let ratings = ['x', 'm', 'a', 's'].map(|attr| format!("{attr}={}", rng.range(1..4001)));
+            format!("{{{}}}", ratings.join(","))
+        });
+
+        Some(format!("{workflows}\n{parts}"))
+    }
+}
+
+/// The workflows by label, and the ratings (x, m, a, s) of every machine part
+#[derive(Debug)]
+pub struct System {
+    pub workflows: HashMap<String, Workflow>,
+    pub parts: Vec<[usize; 4]>,
+}
+
+impl System {
+    pub fn workflow(&self, label: &str) -> Result<&Workflow> {
+        self.workflows
+            .get(label)
+            .ok_or(anyhow!("Unknown workflow: {label}"))
+    }
+}
+
+/// A workflow that parts can get back to after jumping away from it, which would send them around
+/// forever
+pub fn looping_workflow(workflows: &HashMap<String, Workflow>) -> Option<&str> {
+    fn visit<'a>(
+        workflows: &'a HashMap<String, Workflow>,
+        label: &'a str,
+        visiting: &mut HashSet<&'a str>,
+        done: &mut HashSet<&'a str>,
+    ) -> Option<&'a str> {
+        if done.contains(label) {
+            return None;
+        }
+        if !visiting.insert(label) {
+            return Some(label);
+        }
+
+        for rule in workflows
+            .get(label)
+            .map_or(&[][..], |workflow| &workflow.rules)
+        {
+            let (Rule::GreaterThan { result, .. }
+            | Rule::LessThan { result, .. }
+            | Rule::Always { result }) = rule;
+            if let WorkflowResult::Jump(next) = result {
+                if let Some(label) = visit(workflows, next, visiting, done) {
+                    return Some(label);
+                }
+            }
+        }
+
+        visiting.remove(label);
+        done.insert(label);
+        None
+    }
+
+    let (mut visiting, mut done) = (HashSet::new(), HashSet::new());
+    workflows
+        .keys()
+        .find_map(|label| visit(workflows, label, &mut visiting, &mut done))
+}
+
+/// The ratings of a part written as `{x=787,m=2655,a=1222,s=2876}`
+pub fn parse_part(line: &str) -> Result<[usize; 4], ParseError> {
+    let assignments = parse::fields::<4>(parse::delimited(line, '{', '}')?, ",")?;
+
+    let mut parsed = [0; 4];
+    for ((rating, assignment), attr) in parsed.iter_mut().zip(assignments).zip(["x", "m", "a", "s"])
+    {
+        let [name, value] = parse::fields(assignment, "=")?;
+        if name != attr {
+            return Err(ParseError::new(name, format!("`{attr}`")));
+        }
+        *rating = parse::number(value)?;
+    }
+
+    Ok(parsed)
+}
+
+/// How many combinations of ratings within `ranges` end up accepted, starting from rule
+/// `rule_index` of `current_workflow`
+pub fn count_matches(
+    workflows: &HashMap<String, Workflow>,
+    current_workflow: String,
+    rule_index: usize,
+    ranges: [Range<usize>; 4],
+) -> usize {
+    if let Some(workflow) = workflows.get(&current_workflow) {
+        if let Some(rule) = workflow.rules.get(rule_index) {
+            let mut new_ranges = ranges.clone();
+            if let Some(attr) = rule.attr() {
+                new_ranges[attr] = match rule {
+                    Rule::GreaterThan {
+                        attr: _,
+                        value,
+                        result: _,
+                    } => new_ranges[attr].start.max(*value + 1)..new_ranges[attr].end,
+                    Rule::LessThan {
+                        attr: _,
+                        value,
+                        result: _,
+                    } => new_ranges[attr].start..new_ranges[attr].end.min(*value),
+                    Rule::Always { .. } => new_ranges[attr].clone(),
+                }
+            }
+
+            let matches = match rule.result() {
+                // Base case
+                WorkflowResult::Rejected => 0,
+                WorkflowResult::Accepted => new_ranges.iter().map(|range| range.len()).product(),
+
+                // Recursive case (for matching values)
+                WorkflowResult::Jump(next_workflow) => {
+                    count_matches(workflows, next_workflow, 0, new_ranges)
+                }
+            };
+
+            // Recursive case (for non-matching values)
+            let mut new_ranges = ranges.clone();
+            if let Some(attr) = rule.attr() {
+                new_ranges[attr] = match rule {
+                    Rule::GreaterThan {
+                        attr: _,
+                        value,
+                        result: _,
+                    } => new_ranges[attr].start..new_ranges[attr].end.min(*value + 1),
+                    Rule::LessThan {
+                        attr: _,
+                        value,
+                        result: _,
+                    } => new_ranges[attr].start.max(*value)..new_ranges[attr].end,
+                    Rule::Always { .. } => new_ranges[attr].clone(),
+                }
+            }
+            let non_matches = count_matches(
+                workflows,
+                current_workflow.clone(),
+                rule_index + 1,
+                new_ranges,
+            );
+
+            return matches + non_matches;
+        }
+    }
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Example;
+    use itertools::iproduct;
+    use proptest::prelude::*;
+    use rstest::*;
+    use std::path::PathBuf;
+
+    /// The highest rating of the generated parts, small enough to try every combination
+    const MAX_RATING: usize = 6;
+
+    #[rstest]
+    fn test_examples(#[files("fixtures/*.in")] path: PathBuf) -> Result<()> {
+        Example::load(&path)?.check::<Day19>()
+    }
+
+    #[test]
+    fn test_looping_workflows() -> Result<()> {
+        let error = Day19::parse("in{x<5:in,A}\n\n{x=1,m=2,a=3,s=4}\n").unwrap_err();
+        assert_eq!(error.downcast::<ParseError>()?.found(), "in{x<5:in,A}");
+
+        let system =
+            Day19::parse("in{x<5:ab,A}\nab{m>2:cd,R}\ncd{s<7:ab,A}\n\n{x=1,m=2,a=3,s=4}\n");
+        assert!(system.is_err());
+        Ok(())
+    }
+
+    /// Sends every possible part through the workflows, and counts the accepted ones
+    fn naive_count_matches(system: &System) -> usize {
+        let ratings = 1..=MAX_RATING;
+        iproduct!(ratings.clone(), ratings.clone(), ratings.clone(), ratings)
+            .filter(|&(x, m, a, s)| {
+                let mut result = WorkflowResult::Jump("in".to_owned());
+                while let WorkflowResult::Jump(label) = result {
+                    result = system.workflow(&label).unwrap().check(&[x, m, a, s]);
+                }
+                result == WorkflowResult::Accepted
+            })
+            .count()
+    }
+
+    fn label(index: usize) -> String {
+        match index {
+            0 => "in".to_owned(),
+            index => format!("w{index}"),
+        }
+    }
+
+    /* Workflows only jump to the ones after them, so that every part ends up accepted or rejected.
+     * A rule's `target` picks `A`, `R` or one of the following workflows. */
+    fn systems() -> impl Strategy<Value = System> {
+        let rule = (0..4usize, any::<bool>(), 1..=MAX_RATING, 0..6usize);
+        let workflow = (prop::collection::vec(rule, 0..4), 0..6usize);
+
+        prop::collection::vec(workflow, 1..6).prop_map(|workflows| {
+            let count = workflows.len();
+            let result = |index: usize, target: usize| match target {
+                0 => WorkflowResult::Accepted,
+                1 => WorkflowResult::Rejected,
+                target if index + target - 1 < count => {
+                    WorkflowResult::Jump(label(index + target - 1))
+                }
+                _ => WorkflowResult::Accepted,
+            };
+
+            let workflows = workflows
+                .into_iter()
+                .enumerate()
+                .map(|(index, (rules, last))| {
+                    let mut rules = rules
+                        .into_iter()
+                        .map(|(attr, less, value, target)| {
+                            let result = result(index, target);
+                            if less {
+                                Rule::LessThan {
+                                    attr,
+                                    value,
+                                    result,
+                                }
+                            } else {
+                                Rule::GreaterThan {
+                                    attr,
+                                    value,
+                                    result,
+                                }
+                            }
+                        })
+                        .collect::<Vec<_>>();
+                    rules.push(Rule::Always {
+                        result: result(index, last),
+                    });
+
+                    let workflow = Workflow {
+                        label: label(index),
+                        rules,
+                    };
+                    (workflow.label.clone(), workflow)
+                })
+                .collect();
+
+            System {
+                workflows,
+                parts: Vec::new(),
+            }
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_range_splitting(system in systems()) {
+            let ranges = [1..MAX_RATING + 1, 1..MAX_RATING + 1, 1..MAX_RATING + 1, 1..MAX_RATING + 1];
+            prop_assert_eq!(
+                count_matches(&system.workflows, "in".to_owned(), 0, ranges),
+                naive_count_matches(&system)
+            );
+        }
+    }
+}
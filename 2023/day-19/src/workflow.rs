@@ -1,4 +1,4 @@
-use anyhow::anyhow;
+use common::{parse, ParseError};
 use std::str::FromStr;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -69,78 +69,70 @@ impl FromStr for Workflow {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (label, body) = s
-            .split_once("{")
-            .ok_or(anyhow!("Invalid syntax in Workflow: {}", s))?;
-
-        let rules: Vec<Rule> = body
-            .trim_matches('}')
-            .split(',')
-            .map(|rule| {
-                let (expr, result) = match rule.split_once(":") {
-                    Some((expr, result)) => (Some(expr), result),
-                    None => (None, rule),
-                };
-
-                let result = match result {
-                    "A" => WorkflowResult::Accepted,
-                    "R" => WorkflowResult::Rejected,
-                    workflow => WorkflowResult::Jump(workflow.to_owned()),
-                };
-
-                if let Some(expr) = expr {
-                    let attr = expr
-                        .chars()
-                        .nth(0)
-                        .map(|ch| match ch {
-                            'x' => Some(0),
-                            'm' => Some(1),
-                            'a' => Some(2),
-                            's' => Some(3),
-                            _ => None,
-                        })
-                        .flatten()
-                        .ok_or(anyhow!("Invalid syntax in Rule: {}", rule))?;
-
-                    let cmp = expr
-                        .chars()
-                        .nth(1)
-                        .ok_or(anyhow!("Invalid syntax in Rule: {}", rule))?;
-
-                    let (_, value) = expr
-                        .split_once(cmp)
-                        .ok_or(anyhow!("Invalid syntax in Rule: {}", rule))?;
-
-                    match cmp {
-                        '<' => Ok(Rule::LessThan {
-                            attr,
-                            value: value.parse()?,
-                            result,
-                        }),
-                        '>' => Ok(Rule::GreaterThan {
-                            attr,
-                            value: value.parse()?,
-                            result,
-                        }),
-                        _ => Err(anyhow!("Invalid syntax in Rule: {}", rule)),
-                    }
-                } else {
-                    Ok(Rule::Always { result })
-                }
-            })
-            .collect::<Result<_, Self::Err>>()?;
+        let (label, body) = s.split_at(s.find('{').unwrap_or(s.len()));
+        let body = parse::delimited(body, '{', '}')?;
+
+        let rules = parse::list(body, ",")?
+            .into_iter()
+            .map(parse_rule)
+            .collect::<Result<Vec<Rule>, _>>()?;
 
-        if let Rule::Always { .. } = rules.last().unwrap() {
+        if let Some(Rule::Always { .. }) = rules.last() {
             Ok(Self {
                 label: label.to_owned(),
                 rules,
             })
         } else {
-            Err(anyhow!("Last rule in Workflow {} should always match", s))
+            let last_rule = body.rsplit(',').next().unwrap_or(body);
+            Err(ParseError::new(last_rule, "a last rule that always matches").into())
         }
     }
 }
 
+/// A rule like `a<2006:qkq`, or just where to go next
+fn parse_rule(rule: &str) -> Result<Rule, ParseError> {
+    let Some((expr, result)) = rule.split_once(':') else {
+        return Ok(Rule::Always {
+            result: parse_result(rule),
+        });
+    };
+    let result = parse_result(result);
+
+    let attr = match expr.get(..1) {
+        Some("x") => 0,
+        Some("m") => 1,
+        Some("a") => 2,
+        Some("s") => 3,
+        _ => return Err(ParseError::new(expr, "`x`, `m`, `a` or `s`")),
+    };
+    let value = || parse::number(expr.get(2..).unwrap_or_default());
+
+    match expr.get(1..2) {
+        Some("<") => Ok(Rule::LessThan {
+            attr,
+            value: value()?,
+            result,
+        }),
+        Some(">") => Ok(Rule::GreaterThan {
+            attr,
+            value: value()?,
+            result,
+        }),
+        _ => Err(ParseError::new(
+            expr.get(1..2).unwrap_or(&expr[1..]),
+            "`<` or `>`",
+        )),
+    }
+}
+
+fn parse_result(result: &str) -> WorkflowResult {
+    match result {
+        "A" => WorkflowResult::Accepted,
+        "R" => WorkflowResult::Rejected,
+        workflow => WorkflowResult::Jump(workflow.to_owned()),
+    }
+}
+
 impl Workflow {
     pub fn check(&self, part: &[usize; 4]) -> WorkflowResult {
         for rule in &self.rules {
@@ -168,7 +160,7 @@ impl Workflow {
             .last()
             .expect("Valid Workflow last rule should always match")
         {
-            return result.clone();
+            result.clone()
         } else {
             panic!()
         }
@@ -0,0 +1,250 @@
+use anyhow::{bail, Result};
+use common::{parse, Assumption, Params, ParseError, Solution};
+use num::integer::lcm;
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+
+mod module;
+
+pub use module::Module;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    const TITLE: &'static str = "Pulse Propagation";
+
+    /// Every module by label, in its initial state
+    type Input = HashMap<String, Module>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut modules = input
+            .lines()
+            .map(|line| {
+                let (label, outputs) = parse::split_once(line, " -> ")?;
+
+                if label.is_empty() {
+                    bail!(ParseError::new(label, "a module label"));
+                }
+
+                let (t, label) = label.split_at(label.chars().next().map_or(0, char::len_utf8));
+                let outputs = parse::list(outputs, ",")?
+                    .into_iter()
+                    .map(str::to_owned)
+                    .collect();
+
+                let (label, module) = match t {
+                    "%" => (
+                        label,
+                        Module::FlipFlop {
+                            label: label.to_string(),
+                            last_pulse: false,
+                            outputs,
+                        },
+                    ),
+                    "&" => (
+                        label,
+                        Module::Conjunction {
+                            label: label.to_string(),
+                            memory: HashMap::new(),
+                            outputs,
+                        },
+                    ),
+                    "b" => ("broadcaster", Module::Broadcaster { outputs }),
+                    _ => (
+                        label,
+                        Module::Repeater {
+                            label: label.to_string(),
+                            outputs,
+                        },
+                    ),
+                };
+
+                Ok((label.to_string(), module))
+            })
+            .collect::<Result<HashMap<String, Module>>>()?;
+        modules.insert("button".to_string(), Module::Button);
+
+        let mut inputs: HashMap<String, Vec<String>> = HashMap::new();
+        for (label, module) in &modules {
+            for output in module.outputs() {
+                inputs.entry(output).or_default().push(label.clone());
+            }
+        }
+
+        for (label, module) in modules.iter_mut() {
+            if matches!(module, Module::Conjunction { .. }) {
+                module.update_inputs(inputs.remove(label).unwrap_or_default());
+            }
+        }
+
+        Ok(modules)
+    }
+
+    fn part_one(modules: &Self::Input, _: &Params) -> Result<usize> {
+        let modules = power_on(modules);
+
+        let (mut low, mut high) = (0, 0);
+        let mut queue = VecDeque::new();
+        for _ in 0..1000 {
+            queue.push_back(("button".to_string(), false));
+
+            while let Some((sender, pulse)) = queue.pop_front() {
+                let sender_module = modules[sender.as_str()].borrow();
+                for output in sender_module.outputs() {
+                    match pulse {
+                        true => high += 1,
+                        false => low += 1,
+                    }
+
+                    if let Some(output_module) = modules.get(output.as_str()) {
+                        if let Some(output_pulse) = output_module
+                            .borrow_mut()
+                            .process_pulse(sender.to_string(), pulse)
+                        {
+                            queue.push_back((output.clone(), output_pulse))
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(low * high)
+    }
+
+    /* By analyzing the input, we can observe that for `rx` to receive a low pulse, `&hf` must send
+     * a low pulse. For that to happen, all inputs of `&hf` need to send a high pulse. Therefore, we
+     * calculate how many steps it takes for the inputs of `&hf` to send a high pulse and then
+     * return the LCM of those. */
+    fn part_two(modules: &Self::Input, _: &Params) -> Result<usize> {
+        /* The loop below only ends once each of these has sent a high pulse, so without them (e.g.
+         * on the examples) it would never terminate. */
+        if let Some(missing) = ["nd", "pc", "vd", "tx"]
+            .into_iter()
+            .find(|label| !modules.contains_key(*label))
+        {
+            bail!("No module named {missing}, this input has no `rx` machine to turn on");
+        }
+
+        let modules = power_on(modules);
+
+        let mut i: usize = 0;
+        let mut cycle_lengths = HashMap::new();
+        let mut queue = VecDeque::new();
+
+        'outer: loop {
+            queue.push_back(("button".to_string(), false));
+            i += 1;
+
+            while let Some((sender, pulse)) = queue.pop_front() {
+                for output in ["nd", "pc", "vd", "tx"] {
+                    if !cycle_lengths.contains_key(output) && output == sender && pulse {
+                        cycle_lengths.insert(output, i);
+                    }
+
+                    if cycle_lengths.len() == 4 {
+                        break 'outer;
+                    }
+                }
+
+                let sender_module = modules[sender.as_str()].borrow();
+                for output in sender_module.outputs() {
+                    if let Some(output_module) = modules.get(output.as_str()) {
+                        if let Some(output_pulse) = output_module
+                            .borrow_mut()
+                            .process_pulse(sender.to_string(), pulse)
+                        {
+                            queue.push_back((output.clone(), output_pulse))
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(cycle_lengths.values().cloned().fold(1, lcm))
+    }
+
+    fn assumptions(modules: &Self::Input) -> Vec<Assumption> {
+        let feeders = modules
+            .values()
+            .filter(|module| module.outputs().iter().any(|output| output == "rx"))
+            .collect::<Vec<_>>();
+        let conjunction = match feeders[..] {
+            [Module::Conjunction { label, memory, .. }] => Ok((label, memory)),
+            [module] => Err(format!("`{}` isn't a conjunction", module.label())),
+            [] => Err("Nothing sends pulses to `rx`".to_owned()),
+            _ => Err(format!("{} modules send pulses to `rx`", feeders.len())),
+        };
+
+        let inputs = conjunction
+            .as_ref()
+            .map_err(|_| "There's no such conjunction".to_owned());
+        let inputs = inputs.and_then(|(label, memory)| {
+            let mut inputs = memory.keys().map(String::as_str).collect::<Vec<_>>();
+            inputs.sort();
+            if inputs == ["nd", "pc", "tx", "vd"] {
+                Ok(())
+            } else {
+                Err(format!("`{label}` has inputs {}", inputs.join(", ")))
+            }
+        });
+
+        vec![
+            Assumption::new(
+                "`rx` is fed by a single conjunction",
+                conjunction.map(|_| ()),
+            ),
+            Assumption::new(
+                "The inputs of that conjunction are `nd`, `pc`, `vd` and `tx`",
+                inputs,
+            ),
+        ]
+    }
+
+    /* No `generate`: part two only terminates on inputs shaped like the real one, where `rx` is
+     * fed by one conjunction whose inputs are `nd`, `pc`, `vd` and `tx` */
+}
+
+/// A fresh copy of the modules that can be mutated while pulses are sent around
+pub fn power_on(modules: &HashMap<String, Module>) -> HashMap<&str, RefCell<Module>> {
+    modules
+        .iter()
+        .map(|(label, module)| (label.as_str(), RefCell::new(module.clone())))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Example;
+    use rstest::*;
+    use std::path::{Path, PathBuf};
+
+    #[rstest]
+    fn test_examples(#[files("fixtures/*.in")] path: PathBuf) -> Result<()> {
+        Example::load(&path)?.check::<Day20>()
+    }
+
+    #[test]
+    fn test_assumptions() -> Result<()> {
+        let machine = Day20::parse(
+            "broadcaster -> nd, pc, vd, tx\n%nd -> hf\n%pc -> hf\n%vd -> hf\n%tx -> hf\n&hf -> rx",
+        )?;
+        assert!(Day20::assumptions(&machine).iter().all(Assumption::holds));
+
+        let example = Example::load(Path::new("fixtures/example.in"))?;
+        let holds = Day20::assumptions(&Day20::parse(&example.input)?)
+            .iter()
+            .map(Assumption::holds)
+            .collect::<Vec<_>>();
+        assert_eq!(holds, [false, false]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_multibyte_labels() {
+        assert!(Day20::parse("broadcaster -> é\n%é -> ü\n&ü -> rx").is_ok());
+    }
+}
@@ -27,10 +27,10 @@ impl Module {
     pub fn label(&self) -> &str {
         match self {
             Module::Button => "button",
-            Module::Repeater { label, .. } => &label,
+            Module::Repeater { label, .. } => label,
             Module::Broadcaster { .. } => "broadcaster",
-            Module::FlipFlop { label, .. } => &label,
-            Module::Conjunction { label, .. } => &label,
+            Module::FlipFlop { label, .. } => label,
+            Module::Conjunction { label, .. } => label,
         }
     }
 
@@ -60,7 +60,7 @@ impl Module {
             Module::Conjunction { memory, .. } => {
                 memory.insert(sender, pulse);
 
-                if memory.values().all(|&e| e == true) {
+                if memory.values().all(|&e| e) {
                     Some(false)
                 } else {
                     Some(true)
@@ -70,13 +70,10 @@ impl Module {
     }
 
     pub fn update_inputs(&mut self, inputs: Vec<String>) {
-        match self {
-            Module::Conjunction { memory, .. } => {
-                for input in inputs {
-                    memory.insert(input, false);
-                }
+        if let Module::Conjunction { memory, .. } = self {
+            for input in inputs {
+                memory.insert(input, false);
             }
-            _ => (),
         }
     }
 }
@@ -0,0 +1,201 @@
+use anyhow::{anyhow, bail, Result};
+use common::{Assumption, Param, Params, Solution};
+use grid::{Grid, GridTile, Point};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/* Reference:
+* https://github.com/villuna/aoc23/wiki/A-Geometric-solution-to-advent-of-code-2023,-day-21
+*
+* I was so sure that there is a nice geometric solution but I thought it wouldn't work for the general
+* case. It turns out that it works for the input. Advent of Code moment. */
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, GridTile)]
+pub enum Tile {
+    #[tile('S')]
+    Start,
+    #[tile('.')]
+    Space,
+    #[tile('#')]
+    Rock,
+}
+
+pub fn parse_map(input: &str) -> Result<Grid<Tile>> {
+    input.parse()
+}
+
+/// How many plots can be reached from `start_pos` in exactly `steps` steps
+pub fn traverse(map: &Grid<Tile>, start_pos: Point, steps: usize) -> usize {
+    let mut heads = HashSet::new();
+    heads.insert(start_pos);
+
+    for _ in 1..=steps {
+        let mut new_heads = HashSet::new();
+        for &head in &heads {
+            new_heads.extend(
+                map.neighbours4(head)
+                    .filter(|&neighbour| map[neighbour] != Tile::Rock),
+            );
+        }
+        heads = new_heads;
+    }
+
+    heads.len()
+}
+
+/// How many steps the elf takes in part two
+const STEPS: usize = 26501365;
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    const TITLE: &'static str = "Step Counter";
+    const PARAMS: &'static [Param] = &[Param {
+        name: "steps",
+        default: "64",
+        description: "Number of steps the elf takes in part one",
+    }];
+
+    type Input = Garden;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let map = parse_map(input)?;
+
+        let start_pos = map
+            .position(|&tile| tile == Tile::Start)
+            .ok_or(anyhow!("Should have a starting position"))?;
+
+        Ok(Garden { map, start_pos })
+    }
+
+    fn part_one(garden: &Self::Input, params: &Params) -> Result<usize> {
+        Ok(traverse(
+            &garden.map,
+            garden.start_pos,
+            params.get("steps")?,
+        ))
+    }
+
+    fn part_two(garden: &Self::Input, _: &Params) -> Result<usize> {
+        let map = &garden.map;
+        let height = map.height();
+        let start_pos = garden.start_pos;
+
+        let mut frontier = VecDeque::<(usize, Point)>::new();
+        let mut visited = HashMap::new();
+
+        frontier.push_back((0, start_pos));
+
+        while let Some((dist, coord)) = frontier.pop_front() {
+            if visited.contains_key(&coord) {
+                continue;
+            }
+
+            visited.insert(coord, dist);
+
+            frontier.extend(
+                map.neighbours4(coord)
+                    .filter(|&neighbour| map[neighbour] != Tile::Rock)
+                    .map(|position| (dist + 1, position)),
+            );
+        }
+
+        let even_corners = visited
+            .values()
+            .filter(|v| **v % 2 == 0 && **v > 65)
+            .count();
+        let odd_corners = visited
+            .values()
+            .filter(|v| **v % 2 == 1 && **v > 65)
+            .count();
+
+        let n = (STEPS - (height / 2)) / height;
+        if n != 202300 {
+            bail!("The garden doesn't repeat the way the solution expects");
+        }
+
+        let even = n * n;
+        let odd = (n + 1) * (n + 1);
+
+        Ok(odd * visited.values().filter(|v| **v % 2 == 1).count()
+            + even * visited.values().filter(|v| **v % 2 == 0).count()
+            - ((n + 1) * odd_corners)
+            + (n * even_corners))
+    }
+
+    /* Part two counts whole copies of the garden and their corners, which only works if the walk
+     * goes straight out from the middle of one and ends exactly at the edge of another. */
+    fn assumptions(garden: &Self::Input) -> Vec<Assumption> {
+        let map = &garden.map;
+        let (size, start) = (map.height(), garden.start_pos);
+        let clear = map
+            .row(start.row)
+            .iter()
+            .chain(map.column(start.col))
+            .all(|&tile| tile != Tile::Rock);
+
+        vec![
+            Assumption::new(
+                "The garden is square",
+                (map.width() == size)
+                    .then_some(())
+                    .ok_or(format!("It's {} by {size}", map.width())),
+            ),
+            Assumption::new(
+                "`S` is in the middle of the garden",
+                (start == Point::new(size / 2, map.width() / 2))
+                    .then_some(())
+                    .ok_or(format!("It's at {start}")),
+            ),
+            Assumption::check("The row and the column of `S` have no rocks", clear),
+            Assumption::new(
+                format!(
+                    "The garden is 131 plots wide, so that the {STEPS} steps are 65 + 131 × 202300"
+                ),
+                (size == 131)
+                    .then_some(())
+                    .ok_or(format!("It's {size} plots wide")),
+            ),
+        ]
+    }
+
+    /* No `generate`: part two needs a garden 131 plots wide, with `S` in the middle and its row
+     * and column clear, so a generated one would be a copy of the real input with other rocks */
+}
+
+pub struct Garden {
+    pub map: Grid<Tile>,
+    pub start_pos: Point,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Example;
+    use rstest::*;
+    use std::path::{Path, PathBuf};
+
+    #[rstest]
+    fn test_examples(#[files("fixtures/*.in")] path: PathBuf) -> Result<()> {
+        Example::load(&path)?.check::<Day21>()
+    }
+
+    #[test]
+    fn test_assumptions() -> Result<()> {
+        let holds = |input: &str| -> Result<Vec<bool>> {
+            let assumptions = Day21::assumptions(&Day21::parse(input)?);
+            Ok(assumptions.iter().map(Assumption::holds).collect())
+        };
+
+        let example = Example::load(Path::new("fixtures/example.in"))?;
+        assert_eq!(holds(&example.input)?, [true, true, false, false]);
+
+        let mut rows = vec![".".repeat(131); 131];
+        rows[65].replace_range(65..66, "S");
+        rows[3].replace_range(7..8, "#");
+        assert_eq!(holds(&rows.join("\n"))?, [true; 4]);
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+#![no_main]
+
+use common::Solution;
+use day_20::Day20;
+use libfuzzer_sys::fuzz_target;
+
+/* Day 20 has no `FromStr` of its own, the module list is parsed by the solution */
+fuzz_target!(|data: &str| {
+    let _ = Day20::parse(data);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use day_15::Operation;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    let _ = data.parse::<Operation>();
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use day_12::Record;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    let _ = data.parse::<Record>();
+});
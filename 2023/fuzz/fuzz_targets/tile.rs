@@ -0,0 +1,10 @@
+#![no_main]
+
+use day_16::Tile;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    for character in data.chars() {
+        let _ = Tile::try_from(character);
+    }
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use day_19::Workflow;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    let _ = data.parse::<Workflow>();
+});
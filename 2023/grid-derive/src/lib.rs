@@ -0,0 +1,141 @@
+//! `#[derive(GridTile)]`, re-exported by the `grid` crate along with the trait it implements.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input, punctuated::Punctuated, Data, DeriveInput, Error, Expr, ExprLit, Fields,
+    Lit, Token,
+};
+
+/// Maps the variants of an enum to and from the characters of a puzzle input. Every variant is
+/// annotated with its character, followed by the values of its fields when parsed, if any:
+///
+/// ```ignore
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, GridTile)]
+/// enum Tile {
+///     #[tile('#')]
+///     Galaxy,
+///     /// Empty space, one plot wide until the universe expands
+///     #[tile('.', 1)]
+///     Space(usize),
+/// }
+/// ```
+///
+/// This implements `TryFrom<char>` (failing with a `grid::TileError`), `From<Tile> for char`,
+/// `Display` and `grid::GridTile`, which lets whole grids of tiles be parsed with `str::parse`.
+#[proc_macro_derive(GridTile, attributes(tile))]
+pub fn derive_grid_tile(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new_spanned(
+            input,
+            "GridTile can only be derived for enums",
+        ));
+    };
+
+    let mut chars = Vec::new();
+    let mut from_char = Vec::new();
+    let mut to_char = Vec::new();
+    for variant in &data.variants {
+        let attr = variant
+            .attrs
+            .iter()
+            .find(|attr| attr.path().is_ident("tile"))
+            .ok_or(Error::new_spanned(
+                variant,
+                "Expected the character of the variant, like `#[tile('#')]`",
+            ))?;
+        let args = attr.parse_args_with(Punctuated::<Expr, Token![,]>::parse_terminated)?;
+        let mut args = args.into_iter();
+
+        let ch = match args.next() {
+            Some(Expr::Lit(ExprLit {
+                lit: Lit::Char(ch), ..
+            })) => ch,
+            _ => return Err(Error::new_spanned(attr, "Expected a character first")),
+        };
+        if chars
+            .iter()
+            .any(|other: &syn::LitChar| other.value() == ch.value())
+        {
+            return Err(Error::new_spanned(ch, "Another variant has this character"));
+        }
+
+        let values = args.collect::<Vec<_>>();
+        let name = &variant.ident;
+        match &variant.fields {
+            Fields::Unit if values.is_empty() => {
+                from_char.push(quote! { #ch => Ok(Self::#name) });
+                to_char.push(quote! { #name => #ch });
+            }
+            Fields::Unnamed(fields) if fields.unnamed.len() == values.len() => {
+                from_char.push(quote! { #ch => Ok(Self::#name(#(#values),*)) });
+                to_char.push(quote! { #name(..) => #ch });
+            }
+            Fields::Named(_) => {
+                return Err(Error::new_spanned(
+                    variant,
+                    "Variants with named fields aren't supported",
+                ))
+            }
+            _ => {
+                return Err(Error::new_spanned(
+                    attr,
+                    "Expected a value for each field of the variant after its character",
+                ))
+            }
+        }
+        chars.push(ch);
+    }
+
+    let expected = expected(&chars.iter().map(|ch| ch.value()).collect::<Vec<_>>());
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::core::convert::TryFrom<char> for #name #ty_generics #where_clause {
+            type Error = ::grid::TileError;
+
+            fn try_from(ch: char) -> ::core::result::Result<Self, Self::Error> {
+                match ch {
+                    #(#from_char,)*
+                    found => Err(::grid::TileError { found, expected: #expected }),
+                }
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#name #ty_generics> for char #where_clause {
+            fn from(tile: #name #ty_generics) -> char {
+                match tile {
+                    #(#name::#to_char,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::core::fmt::Display for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                use ::core::fmt::Write;
+                f.write_char(char::from(*self))
+            }
+        }
+
+        impl #impl_generics ::grid::GridTile for #name #ty_generics #where_clause {}
+    })
+}
+
+/// The characters the way error messages list them, like "`.`, `#` or `S`"
+fn expected(chars: &[char]) -> String {
+    let quoted = chars.iter().map(|ch| format!("`{ch}`")).collect::<Vec<_>>();
+    match quoted.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} or {last}", rest.join(", ")),
+        Some((last, _)) => last.clone(),
+        None => "nothing".to_owned(),
+    }
+}
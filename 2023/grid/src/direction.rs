@@ -0,0 +1,119 @@
+use crate::Point;
+
+/// One of the four ways to move on a grid. North is towards the first row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Every direction, clockwise from north
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub fn reverse(self) -> Self {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+
+    /// The direction after bouncing off a `/` mirror
+    pub fn reflect_slash(self) -> Self {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::North,
+            Direction::South => Direction::West,
+            Direction::West => Direction::South,
+        }
+    }
+
+    /// The direction after bouncing off a `\` mirror
+    pub fn reflect_backslash(self) -> Self {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::South,
+            Direction::South => Direction::East,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::North | Direction::South)
+    }
+
+    /// How many rows and columns a single step moves
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::North => (-1, 0),
+            Direction::East => (0, 1),
+            Direction::South => (1, 0),
+            Direction::West => (0, -1),
+        }
+    }
+}
+
+impl Point {
+    /// The next point in `direction`, unless it would have a negative coordinate
+    pub fn step(self, direction: Direction) -> Option<Point> {
+        let (rows, cols) = direction.offset();
+        Some(Point::new(
+            self.row.checked_add_signed(rows)?,
+            self.col.checked_add_signed(cols)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+            assert_eq!(direction.reflect_slash().reflect_slash(), direction);
+            assert_eq!(direction.reflect_backslash().reflect_backslash(), direction);
+        }
+
+        assert_eq!(Direction::East.reflect_slash(), Direction::North);
+        assert_eq!(Direction::East.reflect_backslash(), Direction::South);
+    }
+
+    #[test]
+    fn test_step() {
+        let origin = Point::new(0, 0);
+        assert_eq!(origin.step(Direction::North), None);
+        assert_eq!(origin.step(Direction::East), Some(Point::new(0, 1)));
+        assert_eq!(origin.step(Direction::South), Some(Point::new(1, 0)));
+        assert_eq!(origin.step(Direction::West), None);
+    }
+}
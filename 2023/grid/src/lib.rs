@@ -0,0 +1,414 @@
+use anyhow::{bail, Result};
+use common::ParseError;
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+mod direction;
+mod point;
+
+/* The derive refers to this crate as `::grid`, which it has to be able to do from within too */
+extern crate self as grid;
+
+pub use direction::Direction;
+pub use grid_derive::GridTile;
+pub use point::Point;
+
+/// A tile that is written as a single character in puzzle inputs, see `#[derive(GridTile)]`
+pub trait GridTile: Copy + TryFrom<char, Error = TileError> + Into<char> {}
+
+/// A character that isn't any of the tiles of a `GridTile`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TileError {
+    pub found: char,
+    /// The characters of the tiles, like "`.` or `#`"
+    pub expected: &'static str,
+}
+
+impl Display for TileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Expected {}, found `{}`", self.expected, self.found)
+    }
+}
+
+impl std::error::Error for TileError {}
+
+/* Cells are stored row after row in a single `Vec`, so a row is a plain slice and a column is every
+ * `width`-th cell starting from its index in the first row. */
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Result<Self> {
+        if cells.len() != width * height {
+            bail!(
+                "A {width}x{height} grid needs {} cells, got {}",
+                width * height,
+                cells.len()
+            );
+        }
+
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+
+        let mut cells = Vec::with_capacity(width * height);
+        for (row, tiles) in rows.into_iter().enumerate() {
+            if tiles.len() != width {
+                bail!(
+                    "Expected {width} tiles in every row, but row {row} has {}",
+                    tiles.len()
+                );
+            }
+            cells.extend(tiles);
+        }
+
+        Self::new(width, height, cells)
+    }
+
+    /// Parses one row per line, mapping every character to a tile with `tile`. Its errors should
+    /// say what was expected instead of the character, they end up in a `ParseError`.
+    pub fn parse(input: &str, mut tile: impl FnMut(char) -> Result<T>) -> Result<Self> {
+        let mut rows = Vec::new();
+        for line in input.lines() {
+            let row = line
+                .char_indices()
+                .map(|(i, ch)| {
+                    tile(ch).map_err(|err| {
+                        ParseError::new(&line[i..i + ch.len_utf8()], err.to_string())
+                    })
+                })
+                .collect::<Result<Vec<T>, _>>()?;
+
+            if let Some(first) = rows.first().map(Vec::len) {
+                if row.len() != first {
+                    let expected = format!("a row of {first} tiles");
+                    return Err(ParseError::new(line, expected).into());
+                }
+            }
+            rows.push(row);
+        }
+
+        Self::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.row < self.height && point.col < self.width
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.row * self.width + point.col])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.contains(point)
+            .then(|| &mut self.cells[point.row * self.width + point.col])
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + '_ {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        (0..self.height).map(move |row| &self.cells[row * self.width + col])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// Every point of the grid, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Point::new(row, col)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.points().zip(&self.cells)
+    }
+
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, tile)| predicate(tile))
+            .map(|(point, _)| point)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// The next point in `direction`, unless it would leave the grid
+    pub fn step(&self, point: Point, direction: Direction) -> Option<Point> {
+        point.step(direction).filter(|&next| self.contains(next))
+    }
+
+    /// The next point in `direction`, coming back in from the opposite edge when leaving the grid.
+    /// `None` if the grid is empty, as there is nowhere to come back in.
+    pub fn wrapping_step(&self, point: Point, direction: Direction) -> Option<Point> {
+        if self.width == 0 || self.height == 0 {
+            return None;
+        }
+
+        let (rows, cols) = direction.offset();
+        Some(Point::new(
+            (point.row as isize + rows).rem_euclid(self.height as isize) as usize,
+            (point.col as isize + cols).rem_euclid(self.width as isize) as usize,
+        ))
+    }
+
+    /// The points in every direction from `point` that are inside the grid
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(point, direction))
+    }
+
+    /// Like `neighbours4`, but diagonals are also included
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        let diagonals = Direction::ALL.into_iter().filter_map(move |direction| {
+            let next = self.step(point, direction)?;
+            self.step(next, direction.turn_right())
+        });
+
+        self.neighbours4(point).chain(diagonals)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Swaps rows and columns, i.e. mirrors the grid over its main diagonal
+    pub fn transpose(&self) -> Self {
+        Self {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flatten().cloned().collect(),
+        }
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        Self {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flat_map(|col| col.rev()).cloned().collect(),
+        }
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self {
+        Self {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width)
+                .rev()
+                .flat_map(|col| self.column(col))
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        let (width, height) = (self.width, self.height);
+        match self.get(point) {
+            Some(tile) => tile,
+            None => panic!("{point} is outside of a {width}x{height} grid"),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(point) {
+            Some(tile) => tile,
+            None => panic!("{point} is outside of a {width}x{height} grid"),
+        }
+    }
+}
+
+/// Parses one row of tiles per line
+impl<T: GridTile> FromStr for Grid<T> {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s, |ch| {
+            T::try_from(ch).map_err(|err| anyhow::anyhow!(err.expected))
+        })
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for tile in row {
+                write!(f, "{tile}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+    use indoc::indoc;
+
+    const EXAMPLE: &str = indoc! {"
+        abc
+        def
+    "};
+
+    #[test]
+    fn test_parse_and_display() -> Result<()> {
+        let grid = Grid::parse(EXAMPLE, Ok)?;
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(1, 0)], 'd');
+        assert_eq!(grid.get(Point::new(2, 0)), None);
+        assert_eq!(grid.to_string(), EXAMPLE.trim_end());
+
+        assert!(Grid::parse("ab\nc", Ok).is_err());
+        assert!(Grid::parse("ab", |_| Err::<char, _>(anyhow!("nope"))).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rows_and_columns() -> Result<()> {
+        let grid = Grid::parse(EXAMPLE, Ok)?;
+
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            [['a', 'b', 'c'], ['d', 'e', 'f']]
+        );
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.position(|&ch| ch == 'f'), Some(Point::new(1, 2)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_transformations() -> Result<()> {
+        let grid = Grid::parse(EXAMPLE, Ok)?;
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_neighbours() -> Result<()> {
+        let grid = Grid::parse(EXAMPLE, Ok)?;
+
+        let corner = grid.neighbours4(Point::new(0, 0)).collect::<Vec<_>>();
+        assert_eq!(corner, [Point::new(0, 1), Point::new(1, 0)]);
+
+        let middle = grid.neighbours8(Point::new(0, 1)).map(|p| grid[p]);
+        assert_eq!(middle.collect::<String>(), "ceafd");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_steps() -> Result<()> {
+        let grid = Grid::parse(EXAMPLE, Ok)?;
+        let corner = Point::new(0, 2);
+
+        assert_eq!(grid.step(corner, Direction::East), None);
+        assert_eq!(grid.step(corner, Direction::South), Some(Point::new(1, 2)));
+        assert_eq!(
+            grid.wrapping_step(corner, Direction::East),
+            Some(Point::new(0, 0))
+        );
+        assert_eq!(
+            grid.wrapping_step(corner, Direction::North),
+            Some(Point::new(1, 2))
+        );
+
+        let empty = Grid::parse("", Ok)?;
+        assert_eq!(empty.wrapping_step(Point::new(0, 0), Direction::East), None);
+
+        Ok(())
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, GridTile)]
+    enum Tile {
+        #[tile('.')]
+        Space,
+        #[tile('#', 3)]
+        Rock(u8),
+        #[tile('\\')]
+        Mirror,
+    }
+
+    #[test]
+    fn test_grid_tile() -> Result<()> {
+        assert_eq!(Tile::try_from('#'), Ok(Tile::Rock(3)));
+        assert_eq!(char::from(Tile::Rock(5)), '#');
+        assert_eq!(Tile::Mirror.to_string(), "\\");
+        assert_eq!(
+            Tile::try_from('x').unwrap_err().to_string(),
+            "Expected `.`, `#` or `\\`, found `x`"
+        );
+
+        let grid = "#.\\\n..#".parse::<Grid<Tile>>()?;
+        assert_eq!(grid[Point::new(0, 2)], Tile::Mirror);
+        assert_eq!(grid.to_string(), "#.\\\n..#");
+        assert!("#.x".parse::<Grid<Tile>>().is_err());
+
+        Ok(())
+    }
+}